@@ -0,0 +1,21 @@
+#[test]
+fn test_authority_from_str() {
+    use crate::*;
+    let authority: Authority = "Example.COM:8080".parse().unwrap();
+    assert_eq!(
+        authority.get_host(),
+        &AuthorityHost::Domain("example.com".to_string())
+    );
+    assert_eq!(authority.get_port(), &Some(8080));
+    let authority: Authority = "[::1]:443".parse().unwrap();
+    assert_eq!(
+        authority.get_host(),
+        &AuthorityHost::Ipv6(Ipv6Addr::LOCALHOST)
+    );
+    assert_eq!(authority.to_string(), "[::1]:443");
+    let authority: Authority = "127.0.0.1".parse().unwrap();
+    assert_eq!(authority.get_port_or(80), 80);
+    for invalid in ["", "::1", "host:port", "host:70000", "a b", "[::1"] {
+        assert_eq!(invalid.parse::<Authority>(), Err(()));
+    }
+}
@@ -0,0 +1,12 @@
+use crate::*;
+
+/// The host of an authority such as the `Host` header.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AuthorityHost {
+    /// A registered name, lowercased, e.g. `example.com`.
+    Domain(String),
+    /// An IPv4 address, e.g. `127.0.0.1`.
+    Ipv4(Ipv4Addr),
+    /// An IPv6 address, written in brackets in an authority, e.g. `[::1]`.
+    Ipv6(Ipv6Addr),
+}
@@ -0,0 +1,91 @@
+use crate::*;
+
+impl Display for AuthorityHost {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Domain(domain) => write!(f, "{}", domain),
+            Self::Ipv4(ip) => write!(f, "{}", ip),
+            Self::Ipv6(ip) => write!(f, "[{}]", ip),
+        }
+    }
+}
+
+impl FromStr for AuthorityHost {
+    type Err = ();
+
+    fn from_str(host: &str) -> Result<Self, Self::Err> {
+        if let Some(ip) = host.strip_prefix('[').and_then(|tmp| tmp.strip_suffix(']')) {
+            return ip.parse::<Ipv6Addr>().map(Self::Ipv6).map_err(|_| ());
+        }
+        if let Ok(ip) = host.parse::<Ipv4Addr>() {
+            return Ok(Self::Ipv4(ip));
+        }
+        let is_reg_name: bool = !host.is_empty()
+            && host
+                .bytes()
+                .all(|byte| byte.is_ascii_alphanumeric() || b"-._~!$&'()*+,;=%".contains(&byte));
+        if !is_reg_name {
+            return Err(());
+        }
+        Ok(Self::Domain(host.to_ascii_lowercase()))
+    }
+}
+
+impl Display for Authority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.port {
+            Some(port) => write!(f, "{}{}{}", self.host, COLON_SPACE_SYMBOL, port),
+            None => write!(f, "{}", self.host),
+        }
+    }
+}
+
+impl FromStr for Authority {
+    type Err = ();
+
+    fn from_str(authority: &str) -> Result<Self, Self::Err> {
+        let port_index: OptionUsize = match authority.rfind(']') {
+            Some(index) => authority[index..]
+                .find(COLON_SPACE_SYMBOL)
+                .map(|offset| index + offset),
+            None => authority.rfind(COLON_SPACE_SYMBOL),
+        };
+        let (host, port): (&str, &str) = match port_index {
+            Some(index) => (&authority[..index], &authority[index + 1..]),
+            None => (authority, ""),
+        };
+        let port: OptionU16 = if port.is_empty() {
+            None
+        } else if port.bytes().all(|byte| byte.is_ascii_digit()) {
+            Some(port.parse::<u16>().map_err(|_| ())?)
+        } else {
+            return Err(());
+        };
+        Ok(Self::new(host.parse::<AuthorityHost>()?, port))
+    }
+}
+
+impl Authority {
+    /// Creates a new authority.
+    ///
+    /// # Parameters
+    /// - `host`: The host.
+    /// - `port`: The port, if any.
+    ///
+    /// # Returns
+    /// - A new `Authority` instance.
+    pub fn new(host: AuthorityHost, port: OptionU16) -> Self {
+        Self { host, port }
+    }
+
+    /// Returns the port, falling back to a default when none was given.
+    ///
+    /// # Parameters
+    /// - `default_port`: The port implied by the scheme, e.g. `80` for `http`.
+    ///
+    /// # Returns
+    /// - `u16`: The effective port.
+    pub fn get_port_or(&self, default_port: u16) -> u16 {
+        self.port.unwrap_or(default_port)
+    }
+}
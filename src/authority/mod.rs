@@ -0,0 +1,9 @@
+pub(crate) mod cfg;
+pub(crate) mod r#enum;
+pub(crate) mod r#impl;
+pub(crate) mod r#struct;
+pub(crate) mod r#type;
+
+pub use r#enum::*;
+pub use r#struct::*;
+pub use r#type::*;
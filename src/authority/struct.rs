@@ -0,0 +1,10 @@
+use crate::*;
+
+/// A host with an optional port, as sent in the `Host` header or a request target.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Getter)]
+pub struct Authority {
+    /// The host.
+    pub(super) host: AuthorityHost,
+    /// The port, if one was given.
+    pub(super) port: OptionU16,
+}
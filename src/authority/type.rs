@@ -0,0 +1,4 @@
+use crate::*;
+
+/// An optional authority.
+pub type OptionAuthority = Option<Authority>;
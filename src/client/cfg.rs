@@ -0,0 +1,148 @@
+#[tokio::test]
+async fn test_http_client_redirects() {
+    use crate::*;
+    use tokio::net::TcpListener;
+    let listener: TcpListener = TcpListener::bind(SOCKET_ADDR_127_0_0_1).await.unwrap();
+    let port: u16 = listener.local_addr().unwrap().port();
+    tokio::spawn(async move {
+        loop {
+            let (tcp_stream, _) = listener.accept().await.unwrap();
+            let stream: ArcRwLockStream = ArcRwLockStream::from_stream(tcp_stream);
+            let request: Request =
+                Request::http_request_from_stream(&stream, &RequestConfig::default())
+                    .await
+                    .unwrap();
+            let mut response: Response = Response::new();
+            response.set_header(CONNECTION, CLOSE);
+            let location: OptionString = match request.get_path().as_str() {
+                "/start" => Some("/middle?x=1".to_string()),
+                "/middle" => Some(format!("http://127.0.0.1:{}/end", port)),
+                "/see-other" => Some("/end".to_string()),
+                "/loop-a" => Some("/loop-b".to_string()),
+                "/loop-b" => Some("/loop-a".to_string()),
+                "/bad" => Some("http://[".to_string()),
+                _ => None,
+            };
+            match location {
+                Some(location) => {
+                    let status_code: ResponseStatusCode = if request.get_path() == "/middle" {
+                        307
+                    } else if request.get_path() == "/see-other" {
+                        303
+                    } else {
+                        302
+                    };
+                    response
+                        .set_status_code(status_code)
+                        .set_header(LOCATION, location);
+                }
+                None => {
+                    response.set_body(format!(
+                        "{} {} {}",
+                        request.get_method(),
+                        request.get_path(),
+                        request.get_body_string()
+                    ));
+                }
+            }
+            stream.send(&response.build()).await.unwrap();
+        }
+    });
+    let url = |path: &str| -> HttpUrlComponents {
+        HttpUrlComponents::parse(&format!("http://127.0.0.1:{}{}", port, path)).unwrap()
+    };
+    let mut client: HttpClient = HttpClient::new();
+    let get: RequestBuilder = RequestBuilder::new();
+    let response: Response = client.send(&url("/start"), &get).await.unwrap();
+    assert_eq!(response.get_status_code(), &200);
+    assert_eq!(response.get_body_string(), "GET /end ");
+    let mut post: RequestBuilder = RequestBuilder::new();
+    post.set_method(Method::POST).set_body("data");
+    let response: Response = client.send(&url("/see-other"), &post).await.unwrap();
+    assert_eq!(response.get_body_string(), "GET /end ");
+    let response: Response = client.send(&url("/echo"), &post).await.unwrap();
+    assert_eq!(response.get_body_string(), "POST /echo data");
+    assert_eq!(
+        client.send(&url("/loop-a"), &get).await.unwrap_err(),
+        RequestError::RedirectUrlDeadLoop
+    );
+    assert_eq!(
+        client.send(&url("/bad"), &get).await.unwrap_err(),
+        RequestError::RedirectInvalidUrl
+    );
+    client.set_max_redirect_times(1);
+    assert_eq!(
+        client.send(&url("/start"), &get).await.unwrap_err(),
+        RequestError::MaxRedirectTimes
+    );
+    client.set_redirect(false);
+    assert_eq!(
+        client.send(&url("/start"), &get).await.unwrap_err(),
+        RequestError::NeedOpenRedirect
+    );
+}
+
+#[tokio::test]
+async fn test_http_client_redirect_credentials() {
+    use crate::*;
+    use tokio::net::TcpListener;
+    let mut ports: Vec<u16> = Vec::new();
+    let mut listeners: Vec<TcpListener> = Vec::new();
+    for _ in 0..2 {
+        let listener: TcpListener = TcpListener::bind(SOCKET_ADDR_127_0_0_1).await.unwrap();
+        ports.push(listener.local_addr().unwrap().port());
+        listeners.push(listener);
+    }
+    let other_port: u16 = ports[1];
+    for listener in listeners {
+        tokio::spawn(async move {
+            loop {
+                let (tcp_stream, _) = listener.accept().await.unwrap();
+                let stream: ArcRwLockStream = ArcRwLockStream::from_stream(tcp_stream);
+                let request: Request =
+                    Request::http_request_from_stream(&stream, &RequestConfig::default())
+                        .await
+                        .unwrap();
+                let mut response: Response = Response::new();
+                response.set_header(CONNECTION, CLOSE);
+                let location: OptionString = match request.get_path().as_str() {
+                    "/same" => Some("/end".to_string()),
+                    "/cross" => Some(format!("http://127.0.0.1:{}/end", other_port)),
+                    _ => None,
+                };
+                match location {
+                    Some(location) => {
+                        response
+                            .set_status_code(HttpStatus::Found.code())
+                            .set_header(LOCATION, location);
+                    }
+                    None => {
+                        response.set_body(format!(
+                            "{:?} {:?} {:?}",
+                            request.get_header(AUTHORIZATION),
+                            request.get_header(PROXY_AUTHORIZATION),
+                            request.get_header(COOKIE)
+                        ));
+                    }
+                }
+                stream.send(&response.build()).await.unwrap();
+            }
+        });
+    }
+    let client: HttpClient = HttpClient::new();
+    let mut request: RequestBuilder = RequestBuilder::new();
+    request
+        .set_header(AUTHORIZATION, "Bearer token")
+        .set_header(PROXY_AUTHORIZATION, "Basic proxy")
+        .set_header(COOKIE, "session=1");
+    let url = |path: &str| -> HttpUrlComponents {
+        HttpUrlComponents::parse(&format!("http://127.0.0.1:{}{}", ports[0], path)).unwrap()
+    };
+    let response: Response = client.send(&url("/same"), &request).await.unwrap();
+    assert_eq!(
+        response.get_body_string(),
+        "Some(\"Bearer token\") Some(\"Basic proxy\") Some(\"session=1\")"
+    );
+    let response: Response = client.send(&url("/cross"), &request).await.unwrap();
+    assert_eq!(response.get_body_string(), "None None None");
+}
@@ -0,0 +1,489 @@
+use crate::*;
+
+impl Default for HttpClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Debug for HttpClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpClient")
+            .field("config", &self.config)
+            .field("timeouts", &self.timeouts)
+            .field("redirect", &self.redirect)
+            .field("max_redirect_times", &self.max_redirect_times)
+            .field("pool", &self.pool)
+            .finish_non_exhaustive()
+    }
+}
+
+impl HttpClient {
+    /// Creates a new `HttpClient` following up to `DEFAULT_MAX_REDIRECT_TIMES` redirects.
+    ///
+    /// # Returns
+    /// - An initialized `HttpClient` with the default limits and no deadlines.
+    pub fn new() -> Self {
+        Self {
+            config: RequestConfig::default(),
+            timeouts: StreamTimeouts::default(),
+            redirect: true,
+            max_redirect_times: DEFAULT_MAX_REDIRECT_TIMES,
+            pool: ConnectionPool::new(),
+            #[cfg(feature = "tls")]
+            tls_connector: None,
+            #[cfg(feature = "tls")]
+            tls_pool: ConnectionPool::new(),
+        }
+    }
+
+    /// Retrieves the buffer size and limits applied while reading responses.
+    ///
+    /// # Returns
+    /// - `&RequestConfig`: The limits.
+    pub fn get_config(&self) -> &RequestConfig {
+        &self.config
+    }
+
+    /// Sets the buffer size and limits applied while reading responses.
+    ///
+    /// # Parameters
+    /// - `config`: The limits.
+    ///
+    /// # Returns
+    /// - `&mut Self`: A reference to the client, allowing for method chaining.
+    pub fn set_config(&mut self, config: RequestConfig) -> &mut Self {
+        self.config = config;
+        self
+    }
+
+    /// Retrieves the deadlines applied while reading responses and writing requests.
+    ///
+    /// # Returns
+    /// - `&StreamTimeouts`: The deadlines.
+    pub fn get_timeouts(&self) -> &StreamTimeouts {
+        &self.timeouts
+    }
+
+    /// Sets the deadlines applied while reading responses and writing requests.
+    ///
+    /// # Parameters
+    /// - `timeouts`: The deadlines.
+    ///
+    /// # Returns
+    /// - `&mut Self`: A reference to the client, allowing for method chaining.
+    pub fn set_timeouts(&mut self, timeouts: StreamTimeouts) -> &mut Self {
+        self.timeouts = timeouts;
+        self
+    }
+
+    /// Checks whether redirects are followed.
+    ///
+    /// # Returns
+    /// - `bool`: `true` if redirects are followed; otherwise, `false`.
+    pub fn get_redirect(&self) -> bool {
+        self.redirect
+    }
+
+    /// Sets whether redirects are followed.
+    ///
+    /// When disabled, a redirect response fails with `RequestError::NeedOpenRedirect`.
+    ///
+    /// # Parameters
+    /// - `redirect`: Whether redirects are followed.
+    ///
+    /// # Returns
+    /// - `&mut Self`: A reference to the client, allowing for method chaining.
+    pub fn set_redirect(&mut self, redirect: bool) -> &mut Self {
+        self.redirect = redirect;
+        self
+    }
+
+    /// Retrieves the maximum number of redirects followed for a single request.
+    ///
+    /// # Returns
+    /// - `usize`: The maximum number of redirects.
+    pub fn get_max_redirect_times(&self) -> usize {
+        self.max_redirect_times
+    }
+
+    /// Sets the maximum number of redirects followed for a single request.
+    ///
+    /// # Parameters
+    /// - `max_redirect_times`: The maximum number of redirects.
+    ///
+    /// # Returns
+    /// - `&mut Self`: A reference to the client, allowing for method chaining.
+    pub fn set_max_redirect_times(&mut self, max_redirect_times: usize) -> &mut Self {
+        self.max_redirect_times = max_redirect_times;
+        self
+    }
+
+    /// Retrieves the pool of idle `http` connections.
+    ///
+    /// # Returns
+    /// - `&ConnectionPool`: The pool.
+    pub fn get_pool(&self) -> &ConnectionPool {
+        &self.pool
+    }
+
+    /// Sets the pool of idle `http` connections, e.g. to share it between clients
+    /// or to change its limits.
+    ///
+    /// # Parameters
+    /// - `pool`: The pool.
+    ///
+    /// # Returns
+    /// - `&mut Self`: A reference to the client, allowing for method chaining.
+    pub fn set_pool(&mut self, pool: ConnectionPool) -> &mut Self {
+        self.pool = pool;
+        self
+    }
+
+    /// Retrieves the pool of idle `https` connections.
+    ///
+    /// # Returns
+    /// - `&ConnectionPool<TlsClientStream>`: The pool.
+    #[cfg(feature = "tls")]
+    pub fn get_tls_pool(&self) -> &ConnectionPool<TlsClientStream> {
+        &self.tls_pool
+    }
+
+    /// Sets the pool of idle `https` connections.
+    ///
+    /// # Parameters
+    /// - `tls_pool`: The pool.
+    ///
+    /// # Returns
+    /// - `&mut Self`: A reference to the client, allowing for method chaining.
+    #[cfg(feature = "tls")]
+    pub fn set_tls_pool(&mut self, tls_pool: ConnectionPool<TlsClientStream>) -> &mut Self {
+        self.tls_pool = tls_pool;
+        self
+    }
+
+    /// Sets the connector used for `https` URLs.
+    ///
+    /// # Parameters
+    /// - `tls_connector`: The connector holding the trusted roots and ALPN protocols.
+    ///
+    /// # Returns
+    /// - `&mut Self`: A reference to the client, allowing for method chaining.
+    #[cfg(feature = "tls")]
+    pub fn set_tls_connector(&mut self, tls_connector: tokio_rustls::TlsConnector) -> &mut Self {
+        self.tls_connector = Some(tls_connector);
+        self
+    }
+
+    /// Sends a request to a URL and reads the final response, following redirects.
+    ///
+    /// The path, query and `Host` header of the request are taken from the URL.
+    /// Idle pooled connections are reused when the request and the response keep
+    /// the connection alive; a request with an idempotent method is retried once on
+    /// a fresh connection if a reused one turns out to be closed.
+    /// Query parameters of the request are appended to the query of the URL. A `303`
+    /// response, or a `301` or `302` response to a request other than `GET` or `HEAD`,
+    /// is followed with a `GET` request without a body. Interim 1xx responses are skipped.
+    /// When a redirect leads to another origin, including from `https` to `http`,
+    /// the `Authorization`, `Proxy-Authorization` and `Cookie` headers are removed
+    /// so credentials are not sent to a host they were not meant for.
+    ///
+    /// # Parameters
+    /// - `url`: The URL the request is sent to.
+    /// - `request`: The request, whose method, headers and body are sent.
+    ///
+    /// # Returns
+    /// - `Ok`: The final `Response`.
+    /// - `Err`: `RequestError::NeedOpenRedirect` if a redirect is received while redirects
+    ///   are disabled, `RequestError::MaxRedirectTimes` if too many redirects are received,
+    ///   `RequestError::RedirectInvalidUrl` if a redirect has no valid `Location`,
+    ///   `RequestError::RedirectUrlDeadLoop` if a redirect leads back to a visited URL,
+    ///   or an `RequestError` if connecting, sending or reading fails.
+    pub async fn send(
+        &self,
+        url: &HttpUrlComponents,
+        request: &RequestBuilder,
+    ) -> ResponseReaderHandleResult {
+        let mut url: HttpUrlComponents = url.clone();
+        let mut request: RequestBuilder = request.clone();
+        let mut visited: HashSetXxHash3_64<String> = hash_set_xx_hash3_64();
+        let mut redirect_times: usize = 0;
+        loop {
+            let mut url_key: HttpUrlComponents = url.clone();
+            url_key.fragment = None;
+            visited.insert(url_key.to_string());
+            let response: Response = self.send_once(&url, &request).await?;
+            let status_code: ResponseStatusCode = *response.get_status_code();
+            if !Self::is_redirect(status_code) {
+                return Ok(response);
+            }
+            let Some(location) = response.get_header(LOCATION) else {
+                return Ok(response);
+            };
+            if !self.redirect {
+                return Err(RequestError::NeedOpenRedirect);
+            }
+            redirect_times += 1;
+            if redirect_times > self.max_redirect_times {
+                return Err(RequestError::MaxRedirectTimes);
+            }
+            let previous_url: HttpUrlComponents = url.clone();
+            url = url
+                .join(&location)
+                .map_err(|_| RequestError::RedirectInvalidUrl)?;
+            if url.host.is_none() || !(url.protocol.is_http() || url.protocol.is_https()) {
+                return Err(RequestError::RedirectInvalidUrl);
+            }
+            let mut next_key: HttpUrlComponents = url.clone();
+            next_key.fragment = None;
+            if visited.contains(&next_key.to_string()) {
+                return Err(RequestError::RedirectUrlDeadLoop);
+            }
+            if !Self::is_same_origin(&previous_url, &url) {
+                request
+                    .remove_header(AUTHORIZATION)
+                    .remove_header(PROXY_AUTHORIZATION)
+                    .remove_header(COOKIE);
+            }
+            let is_get_or_head: bool =
+                request.get_method().is_get() || request.get_method().is_head();
+            let is_see_other: bool = status_code == HttpStatus::SeeOther.code();
+            let is_moved: bool = status_code == HttpStatus::MovedPermanently.code()
+                || status_code == HttpStatus::Found.code();
+            if is_see_other || (is_moved && !is_get_or_head) {
+                if !request.get_method().is_head() {
+                    request.set_method(Method::GET);
+                }
+                request
+                    .set_body(Vec::new())
+                    .remove_header(CONTENT_LENGTH)
+                    .remove_header(CONTENT_TYPE)
+                    .remove_header(TRANSFER_ENCODING);
+            }
+        }
+    }
+
+    /// Sends a single request to a URL without following redirects.
+    ///
+    /// # Parameters
+    /// - `url`: The URL the request is sent to.
+    /// - `request`: The request, whose method, headers and body are sent.
+    ///
+    /// # Returns
+    /// - `Ok`: The first final `Response`.
+    /// - `Err`: An `RequestError` if the URL is invalid, or connecting, sending or reading fails.
+    async fn send_once(
+        &self,
+        url: &HttpUrlComponents,
+        request: &RequestBuilder,
+    ) -> ResponseReaderHandleResult {
+        let host: &String = url
+            .host
+            .as_ref()
+            .ok_or_else(|| RequestError::InvalidUrl(url.to_string()))?;
+        let port: u16 = url.port.unwrap_or_else(|| url.protocol.get_port());
+        let mut request: RequestBuilder = request.clone();
+        let mut path: RequestPath = url
+            .path
+            .clone()
+            .filter(|path| !path.is_empty())
+            .unwrap_or_else(|| ROOT_PATH.to_string());
+        if let Some(query) = &url.query {
+            path.push_str(QUERY_SYMBOL);
+            path.push_str(query);
+        }
+        request.set_path(path);
+        let host_header: String = match url.port {
+            Some(port) => format!("{}{}{}", host, COLON_SPACE_SYMBOL, port),
+            None => host.clone(),
+        };
+        request.set_header(HOST, host_header);
+        let request: Request = request.build()?;
+        let key: PoolKey = PoolKey::new(url.protocol.clone(), host, port);
+        let address: (&str, u16) = (host.trim_start_matches('[').trim_end_matches(']'), port);
+        if url.protocol.is_http() {
+            return self
+                .exchange_pooled(&self.pool, key, &request, async {
+                    let tcp_stream: TcpStream = Self::connect(address).await?;
+                    let mut stream: ArcRwLockStream = ArcRwLockStream::from_stream(tcp_stream);
+                    stream.set_timeouts(self.timeouts);
+                    Ok(stream)
+                })
+                .await;
+        }
+        if !url.protocol.is_https() {
+            return Err(RequestError::InvalidUrl(url.to_string()));
+        }
+        #[cfg(feature = "tls")]
+        if let Some(tls_connector) = &self.tls_connector {
+            return self
+                .exchange_pooled(&self.tls_pool, key, &request, async {
+                    let tcp_stream: TcpStream = Self::connect(address).await?;
+                    let mut stream: ArcRwLockTlsClientStream =
+                        ArcRwLockStream::tls_connect(tls_connector, address.0, tcp_stream).await?;
+                    stream.set_timeouts(self.timeouts);
+                    Ok(stream)
+                })
+                .await;
+        }
+        Err(RequestError::GetTlsStream)
+    }
+
+    /// Opens a TCP connection.
+    ///
+    /// # Parameters
+    /// - `address`: The host and port to connect to.
+    ///
+    /// # Returns
+    /// - `Ok`: The connected `TcpStream`.
+    /// - `Err`: `RequestError::TcpStreamConnect` if connecting fails.
+    async fn connect(address: (&str, u16)) -> Result<TcpStream, RequestError> {
+        TcpStream::connect(address)
+            .await
+            .map_err(|err| RequestError::TcpStreamConnect(err.to_string()))
+    }
+
+    /// Sends a request over a pooled connection, or over a fresh one if none is idle.
+    ///
+    /// A connection slot of the key is held until the response has been read, so
+    /// the request waits while the key has the maximum number of connections in use.
+    /// If a reused connection fails before a response is read, a request with an
+    /// idempotent method is sent again over a fresh connection. The connection is
+    /// returned to the pool if it can carry another request.
+    ///
+    /// # Parameters
+    /// - `pool`: The pool of idle connections.
+    /// - `key`: The key of the connections that can serve the request.
+    /// - `request`: The request to send.
+    /// - `connect`: The future opening a fresh connection, polled only if needed.
+    ///
+    /// # Returns
+    /// - `Ok`: The first final `Response`.
+    /// - `Err`: An `RequestError` if connecting, sending or reading fails.
+    async fn exchange_pooled<S, F>(
+        &self,
+        pool: &ConnectionPool<S>,
+        key: PoolKey,
+        request: &Request,
+        connect: F,
+    ) -> ResponseReaderHandleResult
+    where
+        S: SplitStream,
+        F: Future<Output = Result<ArcRwLockStream<S>, RequestError>>,
+    {
+        let mut checkout: PoolCheckout<S> = pool.checkout(&key).await?;
+        if let Some(stream) = checkout.take_stream() {
+            match self.exchange(&stream, request).await {
+                Ok(response) => {
+                    if is_connection_reusable(request, &response) {
+                        pool.checkin(key, stream);
+                    }
+                    return Ok(response);
+                }
+                Err(
+                    RequestError::ClientDisconnected
+                    | RequestError::ConnectionReset
+                    | RequestError::Request(_),
+                ) if Self::is_idempotent(request) => {}
+                Err(err) => return Err(err),
+            }
+        }
+        let stream: ArcRwLockStream<S> = connect.await?;
+        let response: Response = self.exchange(&stream, request).await?;
+        if is_connection_reusable(request, &response) {
+            pool.checkin(key, stream);
+        }
+        Ok(response)
+    }
+
+    /// Checks whether a request can be sent again without changing its effect.
+    ///
+    /// # Parameters
+    /// - `request`: The request.
+    ///
+    /// # Returns
+    /// - `bool`: `true` if the method is `GET`, `HEAD`, `OPTIONS`, `TRACE`, `PUT` or `DELETE`;
+    ///   otherwise, `false`.
+    fn is_idempotent(request: &Request) -> bool {
+        request.is_get()
+            || request.is_head()
+            || request.is_options()
+            || request.is_trace()
+            || request.is_put()
+            || request.is_delete()
+    }
+
+    /// Checks whether a status code is a redirect that is followed.
+    ///
+    /// # Parameters
+    /// - `status_code`: The response status code.
+    ///
+    /// # Returns
+    /// - `bool`: `true` for `301`, `302`, `303`, `307` and `308`; otherwise, `false`.
+    fn is_redirect(status_code: ResponseStatusCode) -> bool {
+        [
+            HttpStatus::MovedPermanently,
+            HttpStatus::Found,
+            HttpStatus::SeeOther,
+            HttpStatus::TemporaryRedirect,
+            HttpStatus::PermanentRedirect,
+        ]
+        .iter()
+        .any(|status| status.code() == status_code)
+    }
+
+    /// Checks whether two URLs share the same scheme, host and port.
+    ///
+    /// # Parameters
+    /// - `url`: The first URL.
+    /// - `other`: The second URL.
+    ///
+    /// # Returns
+    /// - `bool`: `true` if both URLs have the same origin; otherwise, `false`.
+    fn is_same_origin(url: &HttpUrlComponents, other: &HttpUrlComponents) -> bool {
+        let host: &str = url.host.as_deref().unwrap_or_default();
+        let other_host: &str = other.host.as_deref().unwrap_or_default();
+        url.protocol == other.protocol
+            && host.eq_ignore_ascii_case(other_host)
+            && url.port.unwrap_or_else(|| url.protocol.get_port())
+                == other.port.unwrap_or_else(|| other.protocol.get_port())
+    }
+
+    /// Writes a request to a connected stream and reads the first final response.
+    ///
+    /// # Parameters
+    /// - `stream`: The connected stream.
+    /// - `request`: The request to send.
+    ///
+    /// # Returns
+    /// - `Ok`: The first `Response` that is not an interim 1xx response, or a
+    ///   `101 Switching Protocols` response.
+    /// - `Err`: An `RequestError` if sending or reading fails.
+    pub(crate) async fn exchange<S>(
+        &self,
+        stream: &ArcRwLockStream<S>,
+        request: &Request,
+    ) -> ResponseReaderHandleResult
+    where
+        S: SplitStream,
+    {
+        stream
+            .send(&request.build())
+            .await
+            .map_err(|err| RequestError::Request(err.to_string()))?;
+        stream
+            .try_flush()
+            .await
+            .map_err(|err| RequestError::Request(err.to_string()))?;
+        loop {
+            let response: Response =
+                Response::http_from_stream(stream, &self.config, request.get_method()).await?;
+            let status_code: ResponseStatusCode = *response.get_status_code();
+            if !(100..200).contains(&status_code)
+                || status_code == HttpStatus::SwitchingProtocols.code()
+            {
+                return Ok(response);
+            }
+        }
+    }
+}
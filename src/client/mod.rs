@@ -0,0 +1,5 @@
+pub(crate) mod cfg;
+pub(crate) mod r#impl;
+pub(crate) mod r#struct;
+
+pub use r#struct::*;
@@ -0,0 +1,27 @@
+use crate::*;
+
+/// A minimal HTTP/1.1 client sending `Request`s and reading `Response`s.
+///
+/// Redirects are followed up to a maximum number of hops, and a redirect back
+/// to a URL already visited is reported as a loop. Connections kept alive by
+/// both sides are pooled and reused, and clones of a client share its pools. `https` URLs require the
+/// `tls` feature and a TLS connector.
+#[derive(Clone)]
+pub struct HttpClient {
+    /// The buffer size and limits applied while reading responses.
+    pub(super) config: RequestConfig,
+    /// The deadlines applied while reading responses and writing requests.
+    pub(super) timeouts: StreamTimeouts,
+    /// Whether redirects are followed.
+    pub(super) redirect: bool,
+    /// The maximum number of redirects followed for a single request.
+    pub(super) max_redirect_times: usize,
+    /// The idle keep-alive connections of `http` URLs.
+    pub(super) pool: ConnectionPool,
+    /// The connector used for `https` URLs.
+    #[cfg(feature = "tls")]
+    pub(super) tls_connector: Option<tokio_rustls::TlsConnector>,
+    /// The idle keep-alive connections of `https` URLs.
+    #[cfg(feature = "tls")]
+    pub(super) tls_pool: ConnectionPool<TlsClientStream>,
+}
@@ -0,0 +1,24 @@
+#[test]
+fn test_content_type_from_str() {
+    use crate::*;
+    assert_eq!(
+        "Application/JSON".parse::<ContentType>(),
+        Ok(ContentType::ApplicationJson)
+    );
+    assert_eq!(
+        "application/json; charset=utf-8".parse::<ContentType>(),
+        Ok(ContentType::Unknown)
+    );
+    assert_eq!(
+        "text/html;charset=utf-8".parse::<ContentType>(),
+        Ok(ContentType::Unknown)
+    );
+    assert_eq!(
+        "multipart/form-data; boundary=abc".parse::<ContentType>(),
+        Ok(ContentType::MultipartFormData)
+    );
+    assert_eq!(
+        "Multipart/Form-Data".parse::<ContentType>(),
+        Ok(ContentType::MultipartFormData)
+    );
+}
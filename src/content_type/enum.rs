@@ -11,6 +11,8 @@ pub enum ContentType {
     TextHtml,
     /// `application/x-www-form-urlencoded`
     FormUrlEncoded,
+    /// `multipart/form-data`
+    MultipartFormData,
     /// Unknown or unrecognized content type
     Unknown,
 }
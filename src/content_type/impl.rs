@@ -80,7 +80,7 @@ impl ContentType {
     /// A string containing the URL-encoded representation of the provided data.
     /// If serialization fails, it returns an empty string.
     fn get_form_url_encoded<T: Serialize + Display>(data: &T) -> String {
-        serde_urlencoded::to_string(data).unwrap_or_else(|_| String::new())
+        url_encoded_to_string(data).unwrap_or_else(|_| String::new())
     }
 
     /// Handles binary data when the `Content-Type` is unknown by formatting the
@@ -121,7 +121,7 @@ impl ContentType {
             Self::TextPlain => Self::get_text_plain(data),
             Self::TextHtml => Self::get_text_html(data),
             Self::FormUrlEncoded => Self::get_form_url_encoded(data),
-            Self::Unknown => Self::get_binary(data),
+            Self::MultipartFormData | Self::Unknown => Self::get_binary(data),
         }
     }
 
@@ -170,6 +170,12 @@ impl FromStr for ContentType {
             _data if _data == TEXT_PLAIN => Ok(Self::TextPlain),
             _data if _data == TEXT_HTML => Ok(Self::TextHtml),
             _data if _data == FORM_URLENCODED => Ok(Self::FormUrlEncoded),
+            _data
+                if _data.split(SEMICOLON).next().unwrap_or_default().trim()
+                    == MULTIPART_FORM_DATA =>
+            {
+                Ok(Self::MultipartFormData)
+            }
             _ => Ok(Self::Unknown),
         }
     }
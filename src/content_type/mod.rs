@@ -1,3 +1,4 @@
+pub(crate) mod cfg;
 pub(crate) mod r#enum;
 pub(crate) mod r#impl;
 
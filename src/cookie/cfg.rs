@@ -0,0 +1,46 @@
+#[test]
+fn test_cookie_parse_all() {
+    use crate::*;
+    let headers: Vec<String> = vec![
+        "session=abc; theme=\"dark\"; invalid; =empty".to_string(),
+        "session=other; lang=en".to_string(),
+    ];
+    let cookies: Cookies = Cookie::parse_all(&headers);
+    assert_eq!(cookies.len(), 3);
+    assert_eq!(cookies.get("session"), Some(&"abc".to_string()));
+    assert_eq!(cookies.get("theme"), Some(&"dark".to_string()));
+    assert_eq!(cookies.get("lang"), Some(&"en".to_string()));
+}
+
+#[test]
+fn test_set_cookie_build() {
+    use crate::*;
+    let mut cookie: SetCookie = SetCookie::new("__Host-id", "\"a1\"");
+    cookie
+        .set_path("/")
+        .set_expires(UNIX_EPOCH + Duration::from_secs(784111777))
+        .set_max_age(3600)
+        .set_secure(true)
+        .set_http_only(true)
+        .set_same_site(SameSite::None)
+        .set_partitioned(true);
+    assert_eq!(
+        cookie.build().unwrap(),
+        "__Host-id=\"a1\"; Path=/; Expires=Sun, 06 Nov 1994 08:49:37 GMT; Max-Age=3600; Secure; HttpOnly; SameSite=None; Partitioned"
+    );
+    cookie.set_domain("example.com");
+    assert_eq!(
+        cookie.build(),
+        Err(CookieError::InvalidHostPrefix("__Host-id".to_string()))
+    );
+    assert_eq!(
+        SetCookie::new("id", "a b").build(),
+        Err(CookieError::InvalidValue("a b".to_string()))
+    );
+    assert_eq!(
+        SetCookie::new("id", "1")
+            .set_same_site(SameSite::None)
+            .build(),
+        Err(CookieError::SecureRequired("SameSite=None".to_string()))
+    );
+}
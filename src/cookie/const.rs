@@ -0,0 +1,46 @@
+/// The `Domain` attribute of a `Set-Cookie` header.
+pub const COOKIE_DOMAIN: &str = "Domain";
+
+/// The `Path` attribute of a `Set-Cookie` header.
+pub const COOKIE_PATH: &str = "Path";
+
+/// The `Expires` attribute of a `Set-Cookie` header.
+pub const COOKIE_EXPIRES: &str = "Expires";
+
+/// The `Max-Age` attribute of a `Set-Cookie` header.
+pub const COOKIE_MAX_AGE: &str = "Max-Age";
+
+/// The `Secure` attribute of a `Set-Cookie` header.
+pub const COOKIE_SECURE: &str = "Secure";
+
+/// The `HttpOnly` attribute of a `Set-Cookie` header.
+pub const COOKIE_HTTP_ONLY: &str = "HttpOnly";
+
+/// The `SameSite` attribute of a `Set-Cookie` header.
+pub const COOKIE_SAME_SITE: &str = "SameSite";
+
+/// The `Partitioned` attribute of a `Set-Cookie` header.
+pub const COOKIE_PARTITIONED: &str = "Partitioned";
+
+/// The `Strict` value of the `SameSite` attribute.
+pub const SAME_SITE_STRICT: &str = "Strict";
+
+/// The `Lax` value of the `SameSite` attribute.
+pub const SAME_SITE_LAX: &str = "Lax";
+
+/// The `None` value of the `SameSite` attribute.
+pub const SAME_SITE_NONE: &str = "None";
+
+/// Cookie name prefix that requires the `Secure` attribute.
+pub const COOKIE_SECURE_PREFIX: &str = "__Secure-";
+
+/// Cookie name prefix that requires `Secure`, `Path=/` and no `Domain`.
+pub const COOKIE_HOST_PREFIX: &str = "__Host-";
+
+/// Abbreviated weekday names used in HTTP dates, starting with Sunday.
+pub(crate) const HTTP_DATE_WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+/// Abbreviated month names used in HTTP dates.
+pub(crate) const HTTP_DATE_MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
@@ -0,0 +1,10 @@
+/// Represents the `SameSite` attribute of a `Set-Cookie` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SameSite {
+    /// Sent only with same-site requests.
+    Strict,
+    /// Sent with same-site requests and top-level cross-site navigations.
+    Lax,
+    /// Sent with all requests, which requires the `Secure` attribute.
+    None,
+}
@@ -0,0 +1,10 @@
+/// Errors raised while building a `Set-Cookie` header value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CookieError {
+    InvalidName(String),
+    InvalidPath(String),
+    InvalidValue(String),
+    InvalidDomain(String),
+    SecureRequired(String),
+    InvalidHostPrefix(String),
+}
@@ -0,0 +1,28 @@
+use crate::*;
+
+impl StdError for CookieError {}
+
+impl Display for CookieError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidName(name) => write!(f, "Invalid cookie name{}{}", COLON_SPACE, name),
+            Self::InvalidPath(path) => write!(f, "Invalid cookie path{}{}", COLON_SPACE, path),
+            Self::InvalidValue(value) => {
+                write!(f, "Invalid cookie value{}{}", COLON_SPACE, value)
+            }
+            Self::InvalidDomain(domain) => {
+                write!(f, "Invalid cookie domain{}{}", COLON_SPACE, domain)
+            }
+            Self::SecureRequired(reason) => {
+                write!(f, "Cookie must be secure{}{}", COLON_SPACE, reason)
+            }
+            Self::InvalidHostPrefix(name) => {
+                write!(
+                    f,
+                    "Cookie with the host prefix must be secure, use path \"/\" and have no domain{}{}",
+                    COLON_SPACE, name
+                )
+            }
+        }
+    }
+}
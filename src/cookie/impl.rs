@@ -0,0 +1,397 @@
+use crate::*;
+
+impl Display for SameSite {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Strict => write!(f, "{}", SAME_SITE_STRICT),
+            Self::Lax => write!(f, "{}", SAME_SITE_LAX),
+            Self::None => write!(f, "{}", SAME_SITE_NONE),
+        }
+    }
+}
+
+impl FromStr for SameSite {
+    type Err = ();
+
+    fn from_str(from_str: &str) -> Result<Self, Self::Err> {
+        match from_str {
+            val if val.eq_ignore_ascii_case(SAME_SITE_STRICT) => Ok(Self::Strict),
+            val if val.eq_ignore_ascii_case(SAME_SITE_LAX) => Ok(Self::Lax),
+            val if val.eq_ignore_ascii_case(SAME_SITE_NONE) => Ok(Self::None),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Cookie {
+    /// Creates a new cookie.
+    ///
+    /// # Parameters
+    /// - `name`: The cookie name.
+    /// - `value`: The cookie value.
+    ///
+    /// # Returns
+    /// - A new `Cookie` instance.
+    pub fn new<N, V>(name: N, value: V) -> Self
+    where
+        N: Into<CookieKey>,
+        V: Into<CookieValue>,
+    {
+        Self {
+            name: name.into(),
+            value: value.into(),
+        }
+    }
+
+    /// Parses the value of a `Cookie` request header.
+    ///
+    /// Pairs are separated by `;`. Pairs without `=` or with an empty name are skipped,
+    /// and double quotes surrounding a value are removed.
+    ///
+    /// # Parameters
+    /// - `header`: The `Cookie` header value.
+    ///
+    /// # Returns
+    /// - `Vec<Cookie>`: The cookies in the order they appear.
+    pub fn parse(header: &str) -> Vec<Self> {
+        header
+            .split(SEMICOLON)
+            .filter_map(|pair| {
+                let (name, value) = pair.split_once(EQUAL)?;
+                let name: &str = name.trim();
+                if name.is_empty() {
+                    return None;
+                }
+                let value: &str = value.trim();
+                let value: &str = value
+                    .strip_prefix('"')
+                    .and_then(|tmp| tmp.strip_suffix('"'))
+                    .unwrap_or(value);
+                Some(Self::new(name, value))
+            })
+            .collect()
+    }
+
+    /// Parses every `Cookie` request header into a map.
+    ///
+    /// When a name is repeated, the first value is kept, since clients send
+    /// cookies with more specific paths first.
+    ///
+    /// # Parameters
+    /// - `headers`: The `Cookie` header values.
+    ///
+    /// # Returns
+    /// - `Cookies`: The cookies keyed by name.
+    pub fn parse_all<'a, I>(headers: I) -> Cookies
+    where
+        I: IntoIterator<Item = &'a String>,
+    {
+        let mut cookies: Cookies = hash_map_xx_hash3_64();
+        for header in headers {
+            for cookie in Self::parse(header) {
+                cookies.entry(cookie.name).or_insert(cookie.value);
+            }
+        }
+        cookies
+    }
+}
+
+impl SetCookie {
+    /// Creates a new `Set-Cookie` builder without attributes.
+    ///
+    /// # Parameters
+    /// - `name`: The cookie name.
+    /// - `value`: The cookie value.
+    ///
+    /// # Returns
+    /// - A new `SetCookie` instance.
+    pub fn new<N, V>(name: N, value: V) -> Self
+    where
+        N: Into<CookieKey>,
+        V: Into<CookieValue>,
+    {
+        Self {
+            name: name.into(),
+            value: value.into(),
+            domain: None,
+            path: None,
+            expires: None,
+            max_age: None,
+            secure: false,
+            http_only: false,
+            same_site: None,
+            partitioned: false,
+        }
+    }
+
+    /// Sets the `Domain` attribute.
+    ///
+    /// # Parameters
+    /// - `domain`: The domain the cookie is sent to.
+    ///
+    /// # Returns
+    /// - `&mut Self`: A mutable reference to the builder for chaining.
+    pub fn set_domain<T: Into<String>>(&mut self, domain: T) -> &mut Self {
+        self.domain = Some(domain.into());
+        self
+    }
+
+    /// Sets the `Path` attribute.
+    ///
+    /// # Parameters
+    /// - `path`: The path prefix the cookie is sent to.
+    ///
+    /// # Returns
+    /// - `&mut Self`: A mutable reference to the builder for chaining.
+    pub fn set_path<T: Into<String>>(&mut self, path: T) -> &mut Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Sets the `Expires` attribute.
+    ///
+    /// # Parameters
+    /// - `expires`: The time at which the cookie expires.
+    ///
+    /// # Returns
+    /// - `&mut Self`: A mutable reference to the builder for chaining.
+    pub fn set_expires(&mut self, expires: SystemTime) -> &mut Self {
+        self.expires = Some(expires);
+        self
+    }
+
+    /// Sets the `Max-Age` attribute.
+    ///
+    /// # Parameters
+    /// - `max_age`: The lifetime of the cookie in seconds, `0` expires it immediately.
+    ///
+    /// # Returns
+    /// - `&mut Self`: A mutable reference to the builder for chaining.
+    pub fn set_max_age(&mut self, max_age: u64) -> &mut Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Sets the `Secure` attribute.
+    ///
+    /// # Parameters
+    /// - `secure`: Whether the cookie is only sent over secure connections.
+    ///
+    /// # Returns
+    /// - `&mut Self`: A mutable reference to the builder for chaining.
+    pub fn set_secure(&mut self, secure: bool) -> &mut Self {
+        self.secure = secure;
+        self
+    }
+
+    /// Sets the `HttpOnly` attribute.
+    ///
+    /// # Parameters
+    /// - `http_only`: Whether the cookie is hidden from scripts.
+    ///
+    /// # Returns
+    /// - `&mut Self`: A mutable reference to the builder for chaining.
+    pub fn set_http_only(&mut self, http_only: bool) -> &mut Self {
+        self.http_only = http_only;
+        self
+    }
+
+    /// Sets the `SameSite` attribute.
+    ///
+    /// # Parameters
+    /// - `same_site`: The cross-site policy of the cookie.
+    ///
+    /// # Returns
+    /// - `&mut Self`: A mutable reference to the builder for chaining.
+    pub fn set_same_site(&mut self, same_site: SameSite) -> &mut Self {
+        self.same_site = Some(same_site);
+        self
+    }
+
+    /// Sets the `Partitioned` attribute.
+    ///
+    /// # Parameters
+    /// - `partitioned`: Whether the cookie is stored in partitioned storage.
+    ///
+    /// # Returns
+    /// - `&mut Self`: A mutable reference to the builder for chaining.
+    pub fn set_partitioned(&mut self, partitioned: bool) -> &mut Self {
+        self.partitioned = partitioned;
+        self
+    }
+
+    /// Validates the cookie and builds the `Set-Cookie` header value.
+    ///
+    /// The name must be a token and the value must consist of cookie octets,
+    /// optionally wrapped in double quotes, as defined by RFC 6265.
+    /// `SameSite=None`, `Partitioned` and the `__Secure-` and `__Host-` name
+    /// prefixes require the `Secure` attribute, and `__Host-` additionally
+    /// requires `Path=/` and no `Domain`.
+    ///
+    /// # Returns
+    /// - `SetCookieResult`: The header value, or a `CookieError` describing the invalid part.
+    pub fn build(&self) -> SetCookieResult {
+        if self.name.is_empty() || !self.name.bytes().all(Self::is_token_byte) {
+            return Err(CookieError::InvalidName(self.name.clone()));
+        }
+        let value: &str = self
+            .value
+            .strip_prefix('"')
+            .and_then(|tmp| tmp.strip_suffix('"'))
+            .unwrap_or(&self.value);
+        if !value.bytes().all(Self::is_cookie_octet) {
+            return Err(CookieError::InvalidValue(self.value.clone()));
+        }
+        if let Some(domain) = &self.domain {
+            let host: &str = domain.strip_prefix('.').unwrap_or(domain);
+            let is_valid: bool = !host.is_empty()
+                && host.split('.').all(|label| {
+                    !label.is_empty()
+                        && label
+                            .bytes()
+                            .all(|byte| byte.is_ascii_alphanumeric() || byte == b'-')
+                });
+            if !is_valid {
+                return Err(CookieError::InvalidDomain(domain.clone()));
+            }
+        }
+        if let Some(path) = &self.path {
+            let is_valid: bool = path.starts_with('/')
+                && path
+                    .bytes()
+                    .all(|byte| !byte.is_ascii_control() && byte != b';');
+            if !is_valid {
+                return Err(CookieError::InvalidPath(path.clone()));
+            }
+        }
+        if !self.secure {
+            if self.same_site == Some(SameSite::None) {
+                return Err(CookieError::SecureRequired(format!(
+                    "{}{}{}",
+                    COOKIE_SAME_SITE, EQUAL, SAME_SITE_NONE
+                )));
+            }
+            if self.partitioned {
+                return Err(CookieError::SecureRequired(COOKIE_PARTITIONED.to_string()));
+            }
+            if self.name.starts_with(COOKIE_SECURE_PREFIX) {
+                return Err(CookieError::SecureRequired(self.name.clone()));
+            }
+        }
+        if self.name.starts_with(COOKIE_HOST_PREFIX)
+            && (!self.secure || self.domain.is_some() || self.path.as_deref() != Some("/"))
+        {
+            return Err(CookieError::InvalidHostPrefix(self.name.clone()));
+        }
+        let mut header: String = format!("{}{}{}", self.name, EQUAL, self.value);
+        if let Some(domain) = &self.domain {
+            header.push_str(&format!(
+                "{}{}{}{}",
+                SEMICOLON_SPACE, COOKIE_DOMAIN, EQUAL, domain
+            ));
+        }
+        if let Some(path) = &self.path {
+            header.push_str(&format!(
+                "{}{}{}{}",
+                SEMICOLON_SPACE, COOKIE_PATH, EQUAL, path
+            ));
+        }
+        if let Some(expires) = self.expires {
+            header.push_str(&format!(
+                "{}{}{}{}",
+                SEMICOLON_SPACE,
+                COOKIE_EXPIRES,
+                EQUAL,
+                Self::format_http_date(expires)
+            ));
+        }
+        if let Some(max_age) = self.max_age {
+            header.push_str(&format!(
+                "{}{}{}{}",
+                SEMICOLON_SPACE, COOKIE_MAX_AGE, EQUAL, max_age
+            ));
+        }
+        if self.secure {
+            header.push_str(&format!("{}{}", SEMICOLON_SPACE, COOKIE_SECURE));
+        }
+        if self.http_only {
+            header.push_str(&format!("{}{}", SEMICOLON_SPACE, COOKIE_HTTP_ONLY));
+        }
+        if let Some(same_site) = self.same_site {
+            header.push_str(&format!(
+                "{}{}{}{}",
+                SEMICOLON_SPACE, COOKIE_SAME_SITE, EQUAL, same_site
+            ));
+        }
+        if self.partitioned {
+            header.push_str(&format!("{}{}", SEMICOLON_SPACE, COOKIE_PARTITIONED));
+        }
+        Ok(header)
+    }
+
+    /// Checks whether a byte is a token character as defined by RFC 9110.
+    ///
+    /// # Parameters
+    /// - `byte`: The byte to check.
+    ///
+    /// # Returns
+    /// - `bool`: `true` if the byte is allowed in a token; otherwise, `false`.
+    fn is_token_byte(byte: u8) -> bool {
+        byte.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&byte)
+    }
+
+    /// Checks whether a byte is a cookie octet as defined by RFC 6265.
+    ///
+    /// # Parameters
+    /// - `byte`: The byte to check.
+    ///
+    /// # Returns
+    /// - `bool`: `true` if the byte is allowed in a cookie value; otherwise, `false`.
+    fn is_cookie_octet(byte: u8) -> bool {
+        matches!(byte, 0x21 | 0x23..=0x2B | 0x2D..=0x3A | 0x3C..=0x5B | 0x5D..=0x7E)
+    }
+
+    /// Formats a time as an IMF-fixdate, e.g. `Sun, 06 Nov 1994 08:49:37 GMT`.
+    ///
+    /// Times before the Unix epoch are formatted as the epoch.
+    ///
+    /// # Parameters
+    /// - `time`: The time to format.
+    ///
+    /// # Returns
+    /// - `String`: The formatted date.
+    fn format_http_date(time: SystemTime) -> String {
+        let seconds: u64 = time
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+        let days: u64 = seconds / 86400;
+        let seconds_of_day: u64 = seconds % 86400;
+        let weekday: usize = ((days + 4) % 7) as usize;
+        let shifted_days: u64 = days + 719468;
+        let era: u64 = shifted_days / 146097;
+        let day_of_era: u64 = shifted_days - era * 146097;
+        let year_of_era: u64 =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year: u64 =
+            day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month: u64 = (5 * day_of_year + 2) / 153;
+        let day: u64 = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month: u64 = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year: u64 = year_of_era + era * 400 + u64::from(month <= 2);
+        format!(
+            "{}, {:02} {} {} {:02}:{:02}:{:02} GMT",
+            HTTP_DATE_WEEKDAYS[weekday],
+            day,
+            HTTP_DATE_MONTHS[(month - 1) as usize],
+            year,
+            seconds_of_day / 3600,
+            seconds_of_day % 3600 / 60,
+            seconds_of_day % 60
+        )
+    }
+}
@@ -0,0 +1,35 @@
+use crate::*;
+
+/// A single name-value pair sent by a client in the `Cookie` header.
+#[derive(Debug, Clone, PartialEq, Eq, Getter, DisplayDebug)]
+pub struct Cookie {
+    /// The cookie name.
+    pub(super) name: CookieKey,
+    /// The cookie value, without surrounding double quotes.
+    pub(super) value: CookieValue,
+}
+
+/// A builder for the value of a `Set-Cookie` response header.
+#[derive(Debug, Clone, PartialEq, Eq, Getter, DisplayDebug)]
+pub struct SetCookie {
+    /// The cookie name.
+    pub(super) name: CookieKey,
+    /// The cookie value.
+    pub(super) value: CookieValue,
+    /// The `Domain` attribute.
+    pub(super) domain: OptionString,
+    /// The `Path` attribute.
+    pub(super) path: OptionString,
+    /// The `Expires` attribute.
+    pub(super) expires: OptionSystemTime,
+    /// The `Max-Age` attribute in seconds.
+    pub(super) max_age: OptionU64,
+    /// Whether the `Secure` attribute is set.
+    pub(super) secure: bool,
+    /// Whether the `HttpOnly` attribute is set.
+    pub(super) http_only: bool,
+    /// The `SameSite` attribute.
+    pub(super) same_site: OptionSameSite,
+    /// Whether the `Partitioned` attribute is set.
+    pub(super) partitioned: bool,
+}
@@ -0,0 +1,16 @@
+use crate::*;
+
+/// The name of a cookie.
+pub type CookieKey = String;
+/// The value of a cookie.
+pub type CookieValue = String;
+/// Cookies sent by a client, keyed by name.
+pub type Cookies = HashMapXxHash3_64<CookieKey, CookieValue>;
+/// An optional cookie value.
+pub type OptionCookieValue = Option<CookieValue>;
+/// An optional `SameSite` attribute.
+pub type OptionSameSite = Option<SameSite>;
+/// An optional cookie expiry time.
+pub type OptionSystemTime = Option<SystemTime>;
+/// The result type returned when building a `Set-Cookie` header value.
+pub type SetCookieResult = Result<String, CookieError>;
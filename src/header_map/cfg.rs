@@ -0,0 +1,34 @@
+#[test]
+fn test_append_and_get_all() {
+    use crate::*;
+    let mut headers: HeaderMap = HeaderMap::new();
+    headers.append("Set-Cookie", "a=1");
+    headers.append(CONTENT_TYPE, TEXT_PLAIN);
+    headers.append(SET_COOKIE, "b=2");
+    assert_eq!(headers.get("SET-COOKIE"), Some(&"a=1".to_string()));
+    assert_eq!(headers.get_all(SET_COOKIE), vec!["a=1", "b=2"]);
+    assert_eq!(headers.len(), 3);
+}
+
+#[test]
+fn test_insert_replaces_in_place() {
+    use crate::*;
+    let mut headers: HeaderMap = HeaderMap::new();
+    headers.append(ACCEPT, TEXT_HTML);
+    headers.append(HOST, "localhost");
+    headers.append(ACCEPT, TEXT_PLAIN);
+    assert_eq!(
+        headers.insert("Accept", APPLICATION_JSON),
+        Some(TEXT_HTML.to_string())
+    );
+    let fields: Vec<&HeaderMapField> = headers.iter().collect();
+    assert_eq!(
+        fields,
+        vec![
+            &(ACCEPT.to_string(), APPLICATION_JSON.to_string()),
+            &(HOST.to_string(), "localhost".to_string())
+        ]
+    );
+    assert_eq!(headers.remove(HOST), vec!["localhost".to_string()]);
+    assert!(!headers.contains_key(HOST));
+}
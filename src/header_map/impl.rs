@@ -0,0 +1,228 @@
+use crate::*;
+
+impl HeaderMap {
+    /// Creates an empty `HeaderMap`.
+    ///
+    /// # Returns
+    /// - An empty `HeaderMap`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty `HeaderMap` with space for at least `capacity` fields.
+    ///
+    /// # Parameters
+    /// - `capacity`: The number of fields to allocate space for.
+    ///
+    /// # Returns
+    /// - An empty `HeaderMap`.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            fields: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Sets a header field, replacing every existing value of the same key.
+    ///
+    /// The new value takes the position of the first replaced value, or is appended
+    /// at the end if the key is not present yet.
+    ///
+    /// # Parameters
+    /// - `key`: The header key, which is lowercased.
+    /// - `value`: The header value.
+    ///
+    /// # Returns
+    /// - `OptionHeaderMapValue`: The first value previously stored for the key, if any.
+    pub fn insert<K, V>(&mut self, key: K, value: V) -> OptionHeaderMapValue
+    where
+        K: Into<HeaderMapKey>,
+        V: Into<HeaderMapValue>,
+    {
+        let key: HeaderMapKey = key.into().to_ascii_lowercase();
+        let value: HeaderMapValue = value.into();
+        match self
+            .fields
+            .iter()
+            .position(|(field_key, _)| *field_key == key)
+        {
+            Some(index) => {
+                let old_value: HeaderMapValue = std::mem::replace(&mut self.fields[index].1, value);
+                let mut current: usize = 0;
+                self.fields.retain(|(field_key, _)| {
+                    let keep: bool = current <= index || *field_key != key;
+                    current += 1;
+                    keep
+                });
+                Some(old_value)
+            }
+            None => {
+                self.fields.push((key, value));
+                None
+            }
+        }
+    }
+
+    /// Appends a header field, keeping every existing value of the same key.
+    ///
+    /// # Parameters
+    /// - `key`: The header key, which is lowercased.
+    /// - `value`: The header value.
+    pub fn append<K, V>(&mut self, key: K, value: V)
+    where
+        K: Into<HeaderMapKey>,
+        V: Into<HeaderMapValue>,
+    {
+        self.fields
+            .push((key.into().to_ascii_lowercase(), value.into()));
+    }
+
+    /// Retrieves the first value of a header field.
+    ///
+    /// # Parameters
+    /// - `key`: The header key, matched case-insensitively.
+    ///
+    /// # Returns
+    /// - `Option<&HeaderMapValue>`: The first value, or `None` if the key is not present.
+    pub fn get<K>(&self, key: K) -> Option<&HeaderMapValue>
+    where
+        K: AsRef<str>,
+    {
+        let key: &str = key.as_ref();
+        self.fields
+            .iter()
+            .find(|(field_key, _)| field_key.eq_ignore_ascii_case(key))
+            .map(|(_, value)| value)
+    }
+
+    /// Retrieves every value of a header field in insertion order.
+    ///
+    /// # Parameters
+    /// - `key`: The header key, matched case-insensitively.
+    ///
+    /// # Returns
+    /// - `Vec<&HeaderMapValue>`: All values of the field, empty if the key is not present.
+    pub fn get_all<K>(&self, key: K) -> Vec<&HeaderMapValue>
+    where
+        K: AsRef<str>,
+    {
+        let key: &str = key.as_ref();
+        self.fields
+            .iter()
+            .filter(|(field_key, _)| field_key.eq_ignore_ascii_case(key))
+            .map(|(_, value)| value)
+            .collect()
+    }
+
+    /// Checks whether a header field is present.
+    ///
+    /// # Parameters
+    /// - `key`: The header key, matched case-insensitively.
+    ///
+    /// # Returns
+    /// - `bool`: `true` if at least one value exists for the key; otherwise, `false`.
+    pub fn contains_key<K>(&self, key: K) -> bool
+    where
+        K: AsRef<str>,
+    {
+        self.get(key).is_some()
+    }
+
+    /// Removes every value of a header field.
+    ///
+    /// # Parameters
+    /// - `key`: The header key, matched case-insensitively.
+    ///
+    /// # Returns
+    /// - `Vec<HeaderMapValue>`: The removed values in insertion order.
+    pub fn remove<K>(&mut self, key: K) -> Vec<HeaderMapValue>
+    where
+        K: AsRef<str>,
+    {
+        let key: &str = key.as_ref();
+        let (removed, fields): (Vec<HeaderMapField>, Vec<HeaderMapField>) =
+            std::mem::take(&mut self.fields)
+                .into_iter()
+                .partition(|(field_key, _)| field_key.eq_ignore_ascii_case(key));
+        self.fields = fields;
+        removed.into_iter().map(|(_, value)| value).collect()
+    }
+
+    /// Removes all header fields.
+    pub fn clear(&mut self) {
+        self.fields.clear();
+    }
+
+    /// Returns the number of header fields, counting every value of repeated fields.
+    ///
+    /// # Returns
+    /// - `usize`: The number of fields.
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Checks whether the map contains no header fields.
+    ///
+    /// # Returns
+    /// - `bool`: `true` if the map is empty; otherwise, `false`.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Returns an iterator over the header fields in insertion order.
+    ///
+    /// # Returns
+    /// - `HeaderMapIter`: An iterator yielding `(key, value)` pairs.
+    pub fn iter(&self) -> HeaderMapIter<'_> {
+        self.fields.iter()
+    }
+}
+
+impl Debug for HeaderMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.fields.iter().map(|(key, value)| (key, value)))
+            .finish()
+    }
+}
+
+impl<'a> IntoIterator for &'a HeaderMap {
+    type Item = &'a HeaderMapField;
+    type IntoIter = HeaderMapIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for HeaderMap {
+    type Item = HeaderMapField;
+    type IntoIter = std::vec::IntoIter<HeaderMapField>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.fields.into_iter()
+    }
+}
+
+impl<K, V> Extend<(K, V)> for HeaderMap
+where
+    K: Into<HeaderMapKey>,
+    V: Into<HeaderMapValue>,
+{
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        for (key, value) in iter {
+            self.append(key, value);
+        }
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for HeaderMap
+where
+    K: Into<HeaderMapKey>,
+    V: Into<HeaderMapValue>,
+{
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        let mut header_map: Self = Self::new();
+        header_map.extend(iter);
+        header_map
+    }
+}
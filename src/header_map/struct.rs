@@ -0,0 +1,12 @@
+use crate::*;
+
+/// An ordered map of HTTP header fields that keeps every value of repeated fields.
+///
+/// Keys are stored lowercased and looked up case-insensitively. Fields are kept in
+/// insertion order, so repeated fields such as `Set-Cookie` are written back in the
+/// order they were added.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct HeaderMap {
+    /// The header fields in insertion order.
+    pub(super) fields: Vec<HeaderMapField>,
+}
@@ -0,0 +1,10 @@
+/// The key type used in a `HeaderMap`.
+pub type HeaderMapKey = String;
+/// The value type used in a `HeaderMap`.
+pub type HeaderMapValue = String;
+/// A single header field stored in a `HeaderMap`.
+pub type HeaderMapField = (HeaderMapKey, HeaderMapValue);
+/// An optional value of a header field.
+pub type OptionHeaderMapValue = Option<HeaderMapValue>;
+/// An iterator over the fields of a `HeaderMap`.
+pub type HeaderMapIter<'a> = std::slice::Iter<'a, HeaderMapField>;
@@ -44,4 +44,41 @@ impl HttpUrlComponents {
         };
         Ok(res)
     }
+
+    /// Resolves a reference, such as the `Location` of a redirect, against this URL.
+    ///
+    /// # Parameters
+    /// - `reference`: An absolute URL, or a path or query relative to this URL.
+    ///
+    /// # Returns
+    /// Returns a `Result` containing the resolved `HttpUrlComponents`, or an
+    /// `Error::InvalidUrl` if this URL or the reference is invalid.
+    pub fn join(&self, reference: &str) -> Result<Self, HttpUrlError> {
+        let base: UrlParser =
+            UrlParser::parse(&self.to_string()).map_err(|_| HttpUrlError::InvalidUrl)?;
+        let joined: UrlParser = base.join(reference).map_err(|_| HttpUrlError::InvalidUrl)?;
+        Self::parse(joined.as_str())
+    }
+}
+
+impl Display for HttpUrlComponents {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.protocol, SCHEME_SEPARATOR)?;
+        if let Some(host) = &self.host {
+            write!(f, "{}", host)?;
+        }
+        if let Some(port) = self.port {
+            write!(f, "{}{}", COLON_SPACE_SYMBOL, port)?;
+        }
+        if let Some(path) = &self.path {
+            write!(f, "{}", path)?;
+        }
+        if let Some(query) = &self.query {
+            write!(f, "{}{}", QUERY_SYMBOL, query)?;
+        }
+        if let Some(fragment) = &self.fragment {
+            write!(f, "{}{}", HASH_SYMBOL, fragment)?;
+        }
+        Ok(())
+    }
 }
@@ -1,17 +1,22 @@
 pub(crate) mod any;
 pub(crate) mod arc_mutex;
 pub(crate) mod arc_rwlock;
+pub(crate) mod authority;
 pub(crate) mod box_rwlock;
+pub(crate) mod client;
 pub(crate) mod content_type;
+pub(crate) mod cookie;
 pub(crate) mod dash_map_xx_hash3_64;
 pub(crate) mod file_extension;
 pub(crate) mod hash_map_xx_hash3_64;
 pub(crate) mod hash_set_xx_hash3_64;
+pub(crate) mod header_map;
 pub(crate) mod http_status;
 pub(crate) mod http_url;
 pub(crate) mod http_version;
 pub(crate) mod json;
 pub(crate) mod methods;
+pub(crate) mod multipart;
 pub(crate) mod option_bool;
 pub(crate) mod option_compress;
 pub(crate) mod option_duration;
@@ -26,28 +31,38 @@ pub(crate) mod option_u32;
 pub(crate) mod option_u64;
 pub(crate) mod option_usize;
 pub(crate) mod option_vec_u8;
+pub(crate) mod pool;
 pub(crate) mod protocol;
 pub(crate) mod rc_rwlock;
 pub(crate) mod request;
 pub(crate) mod response;
+pub(crate) mod server;
 pub(crate) mod stream;
+#[cfg(feature = "tls")]
+pub(crate) mod tls;
 pub(crate) mod upgrade_type;
+pub(crate) mod url_encoded;
 pub(crate) mod websocket_frame;
 
 pub use any::*;
 pub use arc_mutex::*;
 pub use arc_rwlock::*;
+pub use authority::*;
 pub use box_rwlock::*;
+pub use client::*;
 pub use content_type::*;
+pub use cookie::*;
 pub use dash_map_xx_hash3_64::*;
 pub use file_extension::*;
 pub use hash_map_xx_hash3_64::*;
 pub use hash_set_xx_hash3_64::*;
+pub use header_map::*;
 pub use http_status::*;
 pub use http_url::*;
 pub use http_version::*;
 pub use json::*;
 pub use methods::*;
+pub use multipart::*;
 pub use option_bool::*;
 pub use option_compress::*;
 pub use option_duration::*;
@@ -62,18 +77,25 @@ pub use option_u64::*;
 pub use option_u128::*;
 pub use option_usize::*;
 pub use option_vec_u8::*;
+pub use pool::*;
 pub use protocol::*;
 pub use rc_rwlock::*;
 pub use request::*;
 pub use response::*;
+pub use server::*;
 pub use stream::*;
+#[cfg(feature = "tls")]
+pub use tls::*;
 pub use upgrade_type::*;
+pub use url_encoded::*;
 pub use websocket_frame::*;
 
 pub use dashmap;
 pub use http_compress::*;
 pub use http_constant::*;
 pub use tokio;
+#[cfg(feature = "tls")]
+pub use tokio_rustls;
 
 pub(crate) use core::hash::BuildHasherDefault;
 pub(crate) use dashmap::*;
@@ -88,15 +110,24 @@ pub(crate) use std::{
     fmt::{self, Debug, Display},
     hash::Hash,
     io::ErrorKind,
-    net::{IpAddr, SocketAddr},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
     rc::Rc,
     result::Result,
     str::FromStr,
-    sync::Arc,
-    time::Duration,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 pub(crate) use tokio::{
-    io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+    io::{
+        AsyncBufRead, AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt,
+        BufReader, DuplexStream, ReadHalf, Take, WriteHalf,
+    },
     net::TcpStream,
-    sync::{Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard},
+    sync::{Mutex, MutexGuard, Notify, RwLock, RwLockReadGuard, RwLockWriteGuard},
 };
+
+#[cfg(unix)]
+pub(crate) use tokio::net::{UnixStream, unix::UCred};
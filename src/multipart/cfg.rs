@@ -0,0 +1,133 @@
+#[test]
+fn test_multipart_from_slice() {
+    use crate::*;
+    let body: &[u8] = b"preamble\r\n--xyz\r\n\
+        Content-Disposition: form-data; name=\"title\"\r\n\r\n\
+        hello\r\n--xyz  \r\n\
+        Content-Disposition: form-data; name=\"file\"; filename=\"a;b.txt\"\r\n\
+        Content-Type: text/plain\r\n\r\n\
+        line\r\n--xy\r\n--xyz--\r\nepilogue";
+    let parts: Vec<MultipartPart> = multipart_from_slice(body, "xyz").unwrap();
+    assert_eq!(parts.len(), 2);
+    assert_eq!(parts[0].get_name(), "title");
+    assert_eq!(parts[0].get_body_string(), "hello");
+    assert!(!parts[0].is_file());
+    assert_eq!(parts[1].get_filename(), &Some("a;b.txt".to_string()));
+    assert_eq!(parts[1].get_content_type(), &Some(TEXT_PLAIN.to_string()));
+    assert_eq!(parts[1].get_body_string(), "line\r\n--xy");
+    assert_eq!(
+        multipart_from_slice(b"--xyz\r\n\r\ndata", "xyz"),
+        Err(MultipartError::UnexpectedEnd)
+    );
+    let body: &[u8] = b"--xyz\r\nContent-Disposition: form-data; name=\"a\"\r\n\r\n\
+        x\r\n--xyzXYZ\r\ny\r\n--xyz\t\r\n\
+        Content-Disposition: form-data; name=\"b\"\r\n\r\nz\r\n--xyz--";
+    let parts: Vec<MultipartPart> = multipart_from_slice(body, "xyz").unwrap();
+    assert_eq!(parts.len(), 2);
+    assert_eq!(parts[0].get_body_string(), "x\r\n--xyzXYZ\r\ny");
+    assert_eq!(parts[1].get_body_string(), "z");
+    assert_eq!(
+        multipart_boundary("multipart/form-data; boundary=\"a b\""),
+        Some("a b".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_multipart_reader_from_stream() {
+    use crate::*;
+    use tokio::net::{TcpListener, tcp::OwnedReadHalf};
+    let listener: TcpListener = TcpListener::bind(SOCKET_ADDR_127_0_0_1).await.unwrap();
+    let mut client: TcpStream = TcpStream::connect(listener.local_addr().unwrap())
+        .await
+        .unwrap();
+    let body: String = format!(
+        "--b\r\nContent-Disposition: form-data; name=\"file\"; filename=\"big.bin\"\r\n\r\n{}\r\n--b--\r\n",
+        "x".repeat(100)
+    );
+    client
+        .write_all(
+            format!(
+                "POST /upload HTTP/1.1\r\nHost: localhost\r\nContent-Type: multipart/form-data; boundary=b\r\nContent-Length: {}\r\n\r\n{}GET /next HTTP/1.1\r\nHost: localhost\r\n\r\n",
+                body.len(),
+                body
+            )
+            .as_bytes(),
+        )
+        .await
+        .unwrap();
+    let (server, _) = listener.accept().await.unwrap();
+    let stream: ArcRwLockStream = ArcRwLockStream::from_stream(server);
+    let mut config: RequestConfig = RequestConfig::default();
+    config.set_buffer_size(16);
+    let mut read_half: RwLockWriteGuardReadHalf = stream.read_half().await;
+    let reader: &mut BufReader<OwnedReadHalf> = &mut read_half;
+    let request: Request = Request::http_head_from_reader(reader, &config)
+        .await
+        .unwrap();
+    let mut multipart: MultipartReader<Take<&mut BufReader<OwnedReadHalf>>> =
+        request.get_multipart_reader(reader, &config).unwrap();
+    let part: MultipartPart = multipart.next_part().await.unwrap().unwrap();
+    assert_eq!(part.get_filename(), &Some("big.bin".to_string()));
+    let mut size: usize = 0;
+    while let Some(chunk) = multipart.read_chunk().await.unwrap() {
+        assert!(chunk.len() <= 16);
+        size += chunk.len();
+    }
+    assert_eq!(size, 100);
+    assert_eq!(multipart.next_part().await.unwrap(), None);
+    let next: Request = Request::http_from_reader(reader, &config).await.unwrap();
+    assert_eq!(next.get_path(), "/next");
+}
+
+#[tokio::test]
+async fn test_multipart_reader_boundary_prefix() {
+    use crate::*;
+    let body: &[u8] = b"--xyz\r\nContent-Disposition: form-data; name=\"a\"\r\n\r\n\
+        x\r\n--xyzXYZ\r\ny\r\n--xyz \r\n\
+        Content-Disposition: form-data; name=\"b\"\r\n\r\nz\r\n--xyz--\r\n";
+    let mut config: RequestConfig = RequestConfig::default();
+    config.set_buffer_size(3);
+    let mut multipart: MultipartReader<&[u8]> = MultipartReader::new(body, "xyz", &config);
+    let mut bodies: Vec<Vec<u8>> = Vec::new();
+    while multipart.next_part().await.unwrap().is_some() {
+        let mut part_body: Vec<u8> = Vec::new();
+        while let Some(chunk) = multipart.read_chunk().await.unwrap() {
+            part_body.extend_from_slice(&chunk);
+        }
+        bodies.push(part_body);
+    }
+    assert_eq!(bodies, vec![b"x\r\n--xyzXYZ\r\ny".to_vec(), b"z".to_vec()]);
+}
+
+#[tokio::test]
+async fn test_multipart_reader_content_length() {
+    use crate::*;
+    let config: RequestConfig = RequestConfig::default();
+    let heads: [(&[u8], MultipartError); 3] = [
+        (
+            b"POST / HTTP/1.1\r\nHost: localhost\r\nContent-Type: multipart/form-data; boundary=b\r\nContent-Length: 1e3\r\n\r\n",
+            MultipartError::InvalidContentLength("1e3".to_string()),
+        ),
+        (
+            b"POST / HTTP/1.1\r\nHost: localhost\r\nContent-Type: multipart/form-data; boundary=b\r\nContent-Length: 5\r\nContent-Length: 6\r\n\r\n",
+            MultipartError::InvalidContentLength("6".to_string()),
+        ),
+        (
+            b"POST / HTTP/1.1\r\nHost: localhost\r\nContent-Type: multipart/form-data; boundary=b\r\n\r\n",
+            MultipartError::MissingContentLength,
+        ),
+    ];
+    for (head, expected) in heads {
+        let mut reader: BufReader<&[u8]> = BufReader::new(head);
+        let request: Request = Request::http_head_from_reader(&mut reader, &config)
+            .await
+            .unwrap();
+        assert_eq!(
+            request
+                .get_multipart_reader(&mut reader, &config)
+                .err()
+                .unwrap(),
+            expected
+        );
+    }
+}
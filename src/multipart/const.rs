@@ -0,0 +1,17 @@
+/// The `multipart/form-data` media type.
+pub const MULTIPART_FORM_DATA: &str = "multipart/form-data";
+
+/// The prefix shared by all multipart media types.
+pub(crate) const MULTIPART_PREFIX: &str = "multipart/";
+
+/// The `boundary` parameter of a multipart `Content-Type` header.
+pub(crate) const MULTIPART_BOUNDARY: &str = "boundary";
+
+/// The `name` parameter of a `Content-Disposition` header.
+pub(crate) const DISPOSITION_NAME: &str = "name";
+
+/// The `filename` parameter of a `Content-Disposition` header.
+pub(crate) const DISPOSITION_FILENAME: &str = "filename";
+
+/// The dashes preceding a boundary and following the closing boundary.
+pub(crate) const BOUNDARY_DASHES: &str = "--";
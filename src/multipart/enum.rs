@@ -0,0 +1,21 @@
+/// The position of a `MultipartReader` within the body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MultipartReaderState {
+    /// Reading the preamble or the body of a part.
+    Body,
+    /// A delimiter was consumed and the rest of its line is pending.
+    Delimiter,
+    /// The closing delimiter was reached.
+    Done,
+}
+
+/// The outcome of searching a buffer for a multipart delimiter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DelimiterMatch {
+    /// A complete delimiter starts at the index.
+    Found(usize),
+    /// A delimiter may start at the index, but its line is not complete yet.
+    Pending(usize),
+    /// The buffer holds no delimiter.
+    NotFound,
+}
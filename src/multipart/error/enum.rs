@@ -0,0 +1,11 @@
+/// Errors raised while parsing a `multipart/form-data` body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MultipartError {
+    UnexpectedEnd,
+    MissingBoundary,
+    MissingContentLength,
+    MissingContentDisposition,
+    Read(String),
+    InvalidContentLength(String),
+    HeadersTooLarge(usize),
+}
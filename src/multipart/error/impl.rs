@@ -0,0 +1,27 @@
+use crate::*;
+
+impl StdError for MultipartError {}
+
+impl Display for MultipartError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEnd => write!(f, "Multipart body ended before the closing boundary"),
+            Self::MissingBoundary => write!(f, "Missing multipart boundary"),
+            Self::MissingContentLength => write!(f, "Missing multipart content length"),
+            Self::MissingContentDisposition => {
+                write!(f, "Missing multipart content disposition")
+            }
+            Self::Read(err) => write!(f, "Multipart read error{}{}", COLON_SPACE, err),
+            Self::InvalidContentLength(length) => {
+                write!(
+                    f,
+                    "Invalid multipart content length{}{}",
+                    COLON_SPACE, length
+                )
+            }
+            Self::HeadersTooLarge(size) => {
+                write!(f, "Multipart headers too large{}{}", COLON_SPACE, size)
+            }
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use crate::*;
+
+/// Parses a whole `multipart/form-data` body.
+///
+/// The preamble before the first delimiter and the epilogue after the
+/// closing delimiter are ignored.
+///
+/// # Parameters
+/// - `body`: The complete request body.
+/// - `boundary`: The boundary from the `Content-Type` header.
+///
+/// # Returns
+/// - `Ok`: The parts in the order they appear.
+/// - `Err`: A `MultipartError` if the body is truncated or a part is malformed.
+pub fn multipart_from_slice(body: &[u8], boundary: &str) -> MultipartResult {
+    let delimiter: Vec<u8> = format!("{}{}{}", HTTP_BR, BOUNDARY_DASHES, boundary).into_bytes();
+    let opening_len: usize = delimiter.len() - HTTP_BR.len();
+    let mut position: usize = if body.starts_with(&delimiter[HTTP_BR.len()..])
+        && is_delimiter_end(&body[opening_len..]) == Some(true)
+    {
+        opening_len
+    } else {
+        match find_delimiter(body, &delimiter) {
+            DelimiterMatch::Found(index) => index + delimiter.len(),
+            _ => return Err(MultipartError::UnexpectedEnd),
+        }
+    };
+    let mut parts: Vec<MultipartPart> = Vec::new();
+    loop {
+        if body[position..].starts_with(BOUNDARY_DASHES.as_bytes()) {
+            return Ok(parts);
+        }
+        let mut headers: HeaderMap = HeaderMap::new();
+        let mut is_delimiter_line: bool = true;
+        loop {
+            let line_end: usize = find_bytes(&body[position..], HTTP_BR_BYTES)
+                .ok_or(MultipartError::UnexpectedEnd)?;
+            let line: &[u8] = &body[position..position + line_end];
+            position += line_end + HTTP_BR_BYTES.len();
+            if is_delimiter_line {
+                is_delimiter_line = false;
+                continue;
+            }
+            if line.is_empty() {
+                break;
+            }
+            append_multipart_header(&mut headers, line);
+        }
+        let body_end: usize = match find_delimiter(&body[position..], &delimiter) {
+            DelimiterMatch::Found(index) => position + index,
+            _ => return Err(MultipartError::UnexpectedEnd),
+        };
+        parts.push(MultipartPart::from_headers(
+            headers,
+            body[position..body_end].to_vec(),
+        )?);
+        position = body_end + delimiter.len();
+    }
+}
+
+/// Extracts the boundary from a multipart `Content-Type` header value.
+///
+/// # Parameters
+/// - `content_type`: The `Content-Type` header value, e.g. `multipart/form-data; boundary=abc`.
+///
+/// # Returns
+/// - `OptionString`: The boundary, or `None` if the media type is not multipart or has no boundary.
+pub fn multipart_boundary(content_type: &str) -> OptionString {
+    let media_type: &str = content_type.split(SEMICOLON).next()?.trim();
+    if !media_type
+        .to_ascii_lowercase()
+        .starts_with(MULTIPART_PREFIX)
+    {
+        return None;
+    }
+    header_param(content_type, MULTIPART_BOUNDARY).filter(|boundary| !boundary.is_empty())
+}
+
+/// Finds the first occurrence of `needle` in `haystack`.
+///
+/// # Parameters
+/// - `haystack`: The bytes to search.
+/// - `needle`: The non-empty bytes to find.
+///
+/// # Returns
+/// - `OptionUsize`: The index of the first occurrence, or `None` if there is none.
+pub(crate) fn find_bytes(haystack: &[u8], needle: &[u8]) -> OptionUsize {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// Finds the first complete multipart delimiter in `haystack`.
+///
+/// An occurrence of `delimiter` only counts if it is followed by `--`, or by
+/// optional spaces or tabs and a CRLF, so content such as `--boundaryXYZ` is
+/// not mistaken for a delimiter.
+///
+/// # Parameters
+/// - `haystack`: The bytes to search.
+/// - `delimiter`: The delimiter, i.e. a CRLF, two dashes and the boundary.
+///
+/// # Returns
+/// - `DelimiterMatch`: Where the delimiter starts, or whether more bytes are needed to tell.
+pub(crate) fn find_delimiter(haystack: &[u8], delimiter: &[u8]) -> DelimiterMatch {
+    let mut start: usize = 0;
+    while let Some(offset) = find_bytes(&haystack[start..], delimiter) {
+        let index: usize = start + offset;
+        match is_delimiter_end(&haystack[index + delimiter.len()..]) {
+            Some(true) => return DelimiterMatch::Found(index),
+            Some(false) => start = index + 1,
+            None => return DelimiterMatch::Pending(index),
+        }
+    }
+    DelimiterMatch::NotFound
+}
+
+/// Checks the bytes following a boundary for the end of a delimiter line.
+///
+/// # Parameters
+/// - `rest`: The bytes following the boundary.
+///
+/// # Returns
+/// - `Some(true)`: If they start with `--`, or with optional spaces or tabs and a CRLF.
+/// - `Some(false)`: If the boundary is followed by anything else.
+/// - `None`: If more bytes are needed to tell.
+pub(crate) fn is_delimiter_end(rest: &[u8]) -> Option<bool> {
+    if rest.starts_with(BOUNDARY_DASHES.as_bytes()) {
+        return Some(true);
+    }
+    if rest.is_empty() || rest == &BOUNDARY_DASHES.as_bytes()[..1] {
+        return None;
+    }
+    let padding_len: usize = rest
+        .iter()
+        .take_while(|byte| matches!(byte, b' ' | b'\t'))
+        .count();
+    let line_end: &[u8] = &rest[padding_len..];
+    if line_end.starts_with(HTTP_BR_BYTES) {
+        Some(true)
+    } else if HTTP_BR_BYTES.starts_with(line_end) {
+        None
+    } else {
+        Some(false)
+    }
+}
+
+/// Retrieves a parameter from a header value such as `form-data; name="field"`.
+///
+/// Quoted values may contain `;` and backslash escapes, which are removed.
+///
+/// # Parameters
+/// - `value`: The header value.
+/// - `param`: The parameter name, matched case-insensitively.
+///
+/// # Returns
+/// - `OptionString`: The parameter value, or `None` if the parameter is not present.
+pub(crate) fn header_param(value: &str, param: &str) -> OptionString {
+    let mut segments: Vec<String> = vec![String::new()];
+    let mut is_quoted: bool = false;
+    let mut is_escaped: bool = false;
+    for tmp_char in value.chars() {
+        let segment: &mut String = segments.last_mut()?;
+        if is_escaped {
+            is_escaped = false;
+            segment.push(tmp_char);
+        } else if is_quoted && tmp_char == '\\' {
+            is_escaped = true;
+        } else if tmp_char == '"' {
+            is_quoted = !is_quoted;
+        } else if !is_quoted && tmp_char == ';' {
+            segments.push(String::new());
+        } else {
+            segment.push(tmp_char);
+        }
+    }
+    segments.iter().skip(1).find_map(|segment| {
+        let (key, param_value) = segment.split_once(EQUAL)?;
+        key.trim()
+            .eq_ignore_ascii_case(param)
+            .then(|| param_value.trim().to_string())
+    })
+}
+
+/// Parses a part header line and appends it to `headers`, skipping lines without a colon.
+///
+/// # Parameters
+/// - `headers`: The map receiving the header.
+/// - `line`: The header line without the trailing CRLF.
+pub(crate) fn append_multipart_header(headers: &mut HeaderMap, line: &[u8]) {
+    let line: String = String::from_utf8_lossy(line).into_owned();
+    if let Some((key, value)) = line.split_once(COLON_SPACE_SYMBOL) {
+        headers.append(key.trim(), value.trim());
+    }
+}
@@ -0,0 +1,196 @@
+use crate::*;
+
+impl MultipartPart {
+    /// Creates a part from its headers and body.
+    ///
+    /// # Parameters
+    /// - `headers`: The part headers.
+    /// - `body`: The part body.
+    ///
+    /// # Returns
+    /// - `Ok`: The part with its name, file name and content type taken from the headers.
+    /// - `Err`: `MultipartError::MissingContentDisposition` if the part has no field name.
+    pub(crate) fn from_headers(
+        headers: HeaderMap,
+        body: MultipartBody,
+    ) -> Result<Self, MultipartError> {
+        let disposition: &String = headers
+            .get(CONTENT_DISPOSITION)
+            .ok_or(MultipartError::MissingContentDisposition)?;
+        let name: String = header_param(disposition, DISPOSITION_NAME)
+            .ok_or(MultipartError::MissingContentDisposition)?;
+        let filename: OptionString = header_param(disposition, DISPOSITION_FILENAME);
+        let content_type: OptionString = headers.get(CONTENT_TYPE).cloned();
+        Ok(Self {
+            headers,
+            name,
+            filename,
+            content_type,
+            body,
+        })
+    }
+
+    /// Retrieves the body of the part as a UTF-8 encoded string.
+    ///
+    /// Invalid UTF-8 sequences are replaced with the Unicode replacement character.
+    ///
+    /// # Returns
+    /// - `String`: The body content.
+    pub fn get_body_string(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    /// Checks whether the part is a file upload.
+    ///
+    /// # Returns
+    /// - `bool`: `true` if the part has a file name; otherwise, `false`.
+    pub fn is_file(&self) -> bool {
+        self.filename.is_some()
+    }
+}
+
+impl<R> MultipartReader<R>
+where
+    R: AsyncRead + Unpin,
+{
+    /// Creates a reader for a multipart body.
+    ///
+    /// # Parameters
+    /// - `reader`: The reader positioned at the start of the body, ending together with it.
+    /// - `boundary`: The boundary from the `Content-Type` header.
+    /// - `config`: The buffer size and header limit applied while reading.
+    ///
+    /// # Returns
+    /// - A new `MultipartReader` instance.
+    pub fn new(reader: R, boundary: &str, config: &RequestConfig) -> Self {
+        Self {
+            reader,
+            delimiter: format!("{}{}{}", HTTP_BR, BOUNDARY_DASHES, boundary).into_bytes(),
+            buffer: HTTP_BR_BYTES.to_vec(),
+            buffer_size: (*config.get_buffer_size()).max(1),
+            max_header_bytes: *config.get_max_header_bytes(),
+            state: MultipartReaderState::Body,
+        }
+    }
+
+    /// Reads the headers of the next part.
+    ///
+    /// Any unread body of the current part is skipped. The body of the returned
+    /// part is empty and must be read with `read_chunk`. Once the closing
+    /// delimiter is reached, the rest of the reader is drained.
+    ///
+    /// # Returns
+    /// - `Ok(Some)`: The next part without its body.
+    /// - `Ok(None)`: If there are no more parts.
+    /// - `Err`: A `MultipartError` if the body is truncated, a part is malformed or reading fails.
+    pub async fn next_part(&mut self) -> MultipartPartResult {
+        while self.state == MultipartReaderState::Body {
+            self.read_chunk().await?;
+        }
+        if self.state == MultipartReaderState::Done {
+            return Ok(None);
+        }
+        let delimiter_line: Vec<u8> = self.read_line(self.max_header_bytes).await?;
+        if delimiter_line.starts_with(BOUNDARY_DASHES.as_bytes()) {
+            self.state = MultipartReaderState::Done;
+            self.buffer.clear();
+            while self.fill().await? {
+                self.buffer.clear();
+            }
+            return Ok(None);
+        }
+        let mut headers: HeaderMap = HeaderMap::new();
+        let mut header_bytes: usize = 0;
+        loop {
+            let line: Vec<u8> = self
+                .read_line(self.max_header_bytes.saturating_sub(header_bytes))
+                .await?;
+            header_bytes += line.len() + HTTP_BR_BYTES.len();
+            if line.is_empty() {
+                break;
+            }
+            append_multipart_header(&mut headers, &line);
+        }
+        let part: MultipartPart = MultipartPart::from_headers(headers, Vec::new())?;
+        self.state = MultipartReaderState::Body;
+        Ok(Some(part))
+    }
+
+    /// Reads the next chunk of the current part body.
+    ///
+    /// # Returns
+    /// - `Ok(Some)`: A non-empty chunk of the body.
+    /// - `Ok(None)`: If the body of the current part is complete.
+    /// - `Err`: A `MultipartError` if the body is truncated or reading fails.
+    pub async fn read_chunk(&mut self) -> MultipartChunkResult {
+        if self.state != MultipartReaderState::Body {
+            return Ok(None);
+        }
+        loop {
+            match find_delimiter(&self.buffer, &self.delimiter) {
+                DelimiterMatch::Found(0) => {
+                    self.buffer.drain(..self.delimiter.len());
+                    self.state = MultipartReaderState::Delimiter;
+                    return Ok(None);
+                }
+                DelimiterMatch::Pending(0) => {}
+                DelimiterMatch::Found(index) | DelimiterMatch::Pending(index) => {
+                    return Ok(Some(self.buffer.drain(..index).collect()));
+                }
+                DelimiterMatch::NotFound => {
+                    let safe_len: usize =
+                        self.buffer.len().saturating_sub(self.delimiter.len() - 1);
+                    if safe_len > 0 {
+                        return Ok(Some(self.buffer.drain(..safe_len).collect()));
+                    }
+                }
+            }
+            if !self.fill().await? {
+                return Err(MultipartError::UnexpectedEnd);
+            }
+        }
+    }
+
+    /// Reads a line from the buffer, filling it from the reader as needed.
+    ///
+    /// # Parameters
+    /// - `max_length`: The maximum line length in bytes, excluding the CRLF.
+    ///
+    /// # Returns
+    /// - `Ok`: The line without the trailing CRLF.
+    /// - `Err`: A `MultipartError` if the line is too long, the reader ends or reading fails.
+    async fn read_line(&mut self, max_length: usize) -> Result<Vec<u8>, MultipartError> {
+        loop {
+            if let Some(index) = find_bytes(&self.buffer, HTTP_BR_BYTES) {
+                if index > max_length {
+                    return Err(MultipartError::HeadersTooLarge(self.max_header_bytes));
+                }
+                let mut line: Vec<u8> = self.buffer.drain(..index + HTTP_BR_BYTES.len()).collect();
+                line.truncate(index);
+                return Ok(line);
+            }
+            if self.buffer.len() > max_length + HTTP_BR_BYTES.len() {
+                return Err(MultipartError::HeadersTooLarge(self.max_header_bytes));
+            }
+            if !self.fill().await? {
+                return Err(MultipartError::UnexpectedEnd);
+            }
+        }
+    }
+
+    /// Appends the next read from the underlying reader to the buffer.
+    ///
+    /// # Returns
+    /// - `Ok`: `true` if data was read, or `false` at the end of the reader.
+    /// - `Err`: `MultipartError::Read` if reading fails.
+    async fn fill(&mut self) -> Result<bool, MultipartError> {
+        let mut temp_buffer: Vec<u8> = vec![0; self.buffer_size];
+        let len: usize = self
+            .reader
+            .read(&mut temp_buffer)
+            .await
+            .map_err(|err| MultipartError::Read(err.to_string()))?;
+        self.buffer.extend_from_slice(&temp_buffer[..len]);
+        Ok(len > 0)
+    }
+}
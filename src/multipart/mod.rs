@@ -0,0 +1,16 @@
+pub(crate) mod cfg;
+pub(crate) mod r#const;
+pub(crate) mod r#enum;
+pub(crate) mod error;
+pub(crate) mod r#fn;
+pub(crate) mod r#impl;
+pub(crate) mod r#struct;
+pub(crate) mod r#type;
+
+pub use r#const::*;
+pub use error::*;
+pub use r#fn::*;
+pub use r#struct::*;
+pub use r#type::*;
+
+pub(crate) use r#enum::*;
@@ -0,0 +1,36 @@
+use crate::*;
+
+/// A single part of a `multipart/form-data` body.
+#[derive(Debug, Clone, PartialEq, Eq, Getter, DisplayDebug)]
+pub struct MultipartPart {
+    /// The headers of the part, with lowercased keys.
+    pub(super) headers: HeaderMap,
+    /// The field name from the `Content-Disposition` header.
+    pub(super) name: String,
+    /// The file name from the `Content-Disposition` header, if the part is a file.
+    pub(super) filename: OptionString,
+    /// The `Content-Type` of the part, if sent.
+    pub(super) content_type: OptionString,
+    /// The body of the part, empty when the part is read from a `MultipartReader`.
+    pub(super) body: MultipartBody,
+}
+
+/// Reads a `multipart/form-data` body part by part without buffering whole parts.
+///
+/// The reader must end together with the multipart body, which
+/// `Request::get_multipart_reader` ensures by limiting it to the content length.
+#[derive(Debug)]
+pub struct MultipartReader<R> {
+    /// The underlying reader positioned at the start of the body.
+    pub(super) reader: R,
+    /// The delimiter preceding each part, i.e. CRLF, two dashes and the boundary.
+    pub(super) delimiter: Vec<u8>,
+    /// Data read from `reader` but not yet returned.
+    pub(super) buffer: Vec<u8>,
+    /// The number of bytes read from `reader` at once.
+    pub(super) buffer_size: usize,
+    /// The maximum total length of the header lines of a part.
+    pub(super) max_header_bytes: usize,
+    /// The position of the reader within the body.
+    pub(super) state: MultipartReaderState,
+}
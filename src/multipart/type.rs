@@ -0,0 +1,12 @@
+use crate::*;
+
+/// The body of a multipart part.
+pub type MultipartBody = Vec<u8>;
+/// An optional multipart part.
+pub type OptionMultipartPart = Option<MultipartPart>;
+/// The result type returned when parsing a whole multipart body.
+pub type MultipartResult = Result<Vec<MultipartPart>, MultipartError>;
+/// The result type returned when reading the next part from a stream.
+pub type MultipartPartResult = Result<OptionMultipartPart, MultipartError>;
+/// The result type returned when reading a chunk of a part body from a stream.
+pub type MultipartChunkResult = Result<OptionVecU8, MultipartError>;
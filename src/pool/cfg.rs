@@ -0,0 +1,150 @@
+#[tokio::test]
+async fn test_connection_pool_reuse() {
+    use crate::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::net::TcpListener;
+    let listener: TcpListener = TcpListener::bind(SOCKET_ADDR_127_0_0_1).await.unwrap();
+    let port: u16 = listener.local_addr().unwrap().port();
+    let accepted: Arc<AtomicUsize> = Arc::new(AtomicUsize::new(0));
+    let server_accepted: Arc<AtomicUsize> = Arc::clone(&accepted);
+    tokio::spawn(async move {
+        loop {
+            let (tcp_stream, _) = listener.accept().await.unwrap();
+            server_accepted.fetch_add(1, Ordering::SeqCst);
+            tokio::spawn(async move {
+                let stream: ArcRwLockStream = ArcRwLockStream::from_stream(tcp_stream);
+                while let Ok(request) =
+                    Request::http_request_from_stream(&stream, &RequestConfig::default()).await
+                {
+                    let mut response: Response = Response::new();
+                    response.set_body(request.get_path().clone());
+                    if request.get_path() == "/close" {
+                        response.set_header(CONNECTION, CLOSE);
+                    }
+                    stream.send(&response.build()).await.unwrap();
+                    if request.get_path() == "/close" {
+                        break;
+                    }
+                }
+            });
+        }
+    });
+    let url = |path: &str| -> HttpUrlComponents {
+        HttpUrlComponents::parse(&format!("http://127.0.0.1:{}{}", port, path)).unwrap()
+    };
+    let key: PoolKey = PoolKey::new(Protocol::HTTP, "127.0.0.1", port);
+    let client: HttpClient = HttpClient::new();
+    let get: RequestBuilder = RequestBuilder::new();
+    for path in ["/a", "/b", "/c"] {
+        let response: Response = client.send(&url(path), &get).await.unwrap();
+        assert_eq!(response.get_body_string(), path);
+    }
+    assert_eq!(accepted.load(Ordering::SeqCst), 1);
+    assert_eq!(client.get_pool().idle_count(&key), 1);
+    client.send(&url("/close"), &get).await.unwrap();
+    assert_eq!(client.get_pool().idle_count(&key), 0);
+    client.send(&url("/d"), &get).await.unwrap();
+    assert_eq!(accepted.load(Ordering::SeqCst), 2);
+    let mut close: RequestBuilder = RequestBuilder::new();
+    close.set_header(CONNECTION, CLOSE);
+    client.send(&url("/e"), &close).await.unwrap();
+    assert_eq!(client.get_pool().idle_count(&key), 0);
+}
+
+#[tokio::test]
+async fn test_connection_pool_eviction() {
+    use crate::*;
+    use tokio::net::TcpListener;
+    let listener: TcpListener = TcpListener::bind(SOCKET_ADDR_127_0_0_1).await.unwrap();
+    let address: SocketAddr = listener.local_addr().unwrap();
+    let key: PoolKey = PoolKey::new(Protocol::HTTP, "LOCALHOST", address.port());
+    assert_eq!(key.get_host(), "localhost");
+    let mut pool: ConnectionPool = ConnectionPool::new();
+    pool.set_max_idle_per_host(1);
+    let mut peers: Vec<TcpStream> = Vec::new();
+    for _ in 0..2 {
+        let client: TcpStream = TcpStream::connect(address).await.unwrap();
+        peers.push(listener.accept().await.unwrap().0);
+        pool.checkin(key.clone(), ArcRwLockStream::from_stream(client));
+    }
+    assert_eq!(pool.idle_count(&key), 1);
+    assert!(pool.checkout(&key).await.unwrap().get_stream().is_some());
+    assert!(pool.checkout(&key).await.unwrap().get_stream().is_none());
+    let client: TcpStream = TcpStream::connect(address).await.unwrap();
+    let peer: TcpStream = listener.accept().await.unwrap().0;
+    pool.checkin(key.clone(), ArcRwLockStream::from_stream(client));
+    drop(peer);
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(pool.checkout(&key).await.unwrap().get_stream().is_none());
+    assert_eq!(pool.idle_count(&key), 0);
+    pool.set_idle_timeout(Duration::from_millis(20));
+    let client: TcpStream = TcpStream::connect(address).await.unwrap();
+    peers.push(listener.accept().await.unwrap().0);
+    pool.checkin(key.clone(), ArcRwLockStream::from_stream(client));
+    assert_eq!(pool.idle_count(&key), 1);
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    pool.evict_idle();
+    assert_eq!(pool.idle_count(&key), 0);
+    let client: TcpStream = TcpStream::connect(address).await.unwrap();
+    peers.push(listener.accept().await.unwrap().0);
+    pool.checkin(key.clone(), ArcRwLockStream::from_stream(client));
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(pool.checkout(&key).await.unwrap().get_stream().is_none());
+}
+
+#[tokio::test]
+async fn test_connection_pool_max_connections() {
+    use crate::*;
+    let key: PoolKey = PoolKey::new(Protocol::HTTP, "localhost", 80);
+    let mut pool: ConnectionPool<DuplexStream> = ConnectionPool::new();
+    pool.set_max_connections_per_host(2);
+    let first: PoolCheckout<DuplexStream> = pool.checkout(&key).await.unwrap();
+    let _second: PoolCheckout<DuplexStream> = pool.checkout(&key).await.unwrap();
+    assert!(
+        tokio::time::timeout(Duration::from_millis(50), pool.checkout(&key))
+            .await
+            .is_err()
+    );
+    let other: PoolKey = PoolKey::new(Protocol::HTTPS, "localhost", 80);
+    assert!(pool.checkout(&other).await.is_ok());
+    let waiting: tokio::task::JoinHandle<bool> = tokio::spawn({
+        let pool: ConnectionPool<DuplexStream> = pool.clone();
+        let key: PoolKey = key.clone();
+        async move { pool.checkout(&key).await.is_ok() }
+    });
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert!(!waiting.is_finished());
+    drop(first);
+    assert!(waiting.await.unwrap());
+}
+
+#[tokio::test]
+async fn test_connection_pool_shared_limit() {
+    use crate::*;
+    let key: PoolKey = PoolKey::new(Protocol::HTTP, "localhost", 80);
+    let mut pool: ConnectionPool<DuplexStream> = ConnectionPool::new();
+    pool.set_max_connections_per_host(1);
+    let first: PoolCheckout<DuplexStream> = pool.checkout(&key).await.unwrap();
+    let mut shared: ConnectionPool<DuplexStream> = pool.clone();
+    shared.set_max_connections_per_host(1);
+    assert_eq!(pool.in_use_count(&key), 1);
+    assert!(
+        tokio::time::timeout(Duration::from_millis(50), pool.checkout(&key))
+            .await
+            .is_err()
+    );
+    let waiting: tokio::task::JoinHandle<bool> = tokio::spawn({
+        let pool: ConnectionPool<DuplexStream> = pool.clone();
+        let key: PoolKey = key.clone();
+        async move { pool.checkout(&key).await.is_ok() }
+    });
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert!(!waiting.is_finished());
+    shared.set_max_connections_per_host(2);
+    assert_eq!(pool.get_max_connections_per_host(), 2);
+    assert!(waiting.await.unwrap());
+    assert_eq!(pool.in_use_count(&key), 1);
+    drop(first);
+    assert_eq!(pool.in_use_count(&key), 0);
+    assert!(pool.permits.is_empty());
+}
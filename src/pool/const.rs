@@ -0,0 +1,8 @@
+/// Default maximum number of idle connections kept per host.
+pub const DEFAULT_POOL_MAX_IDLE_PER_HOST: usize = 8;
+
+/// Default maximum number of connections in use at once per host.
+pub const DEFAULT_POOL_MAX_CONNECTIONS_PER_HOST: usize = 32;
+
+/// Default time in seconds an idle connection is kept before it is evicted.
+pub const DEFAULT_POOL_IDLE_TIMEOUT_SECS: u64 = 90;
@@ -0,0 +1,22 @@
+use crate::*;
+
+/// Checks whether the connection of an exchange can be returned to a pool.
+///
+/// # Parameters
+/// - `request`: The request sent.
+/// - `response`: The response read.
+///
+/// # Returns
+/// - `bool`: `true` if both sides keep the connection alive, the protocol is not
+///   switched and the end of the response body did not depend on the connection
+///   being closed; otherwise, `false`.
+pub fn is_connection_reusable(request: &Request, response: &Response) -> bool {
+    let is_framed: bool = response.is_chunked()
+        || response.get_headers().contains_key(CONTENT_LENGTH)
+        || request.get_method().is_head()
+        || matches!(response.get_status_code(), 204 | 304);
+    request.is_enable_keep_alive()
+        && *response.get_status_code() != 101
+        && response.is_enable_keep_alive()
+        && is_framed
+}
@@ -0,0 +1,346 @@
+use crate::*;
+
+impl PoolKey {
+    /// Creates a new `PoolKey`.
+    ///
+    /// # Parameters
+    /// - `protocol`: The protocol of the connections.
+    /// - `host`: The host the connections are made to, matched case-insensitively.
+    /// - `port`: The port the connections are made to.
+    ///
+    /// # Returns
+    /// - A new `PoolKey` instance.
+    pub fn new(protocol: Protocol, host: &str, port: u16) -> Self {
+        Self {
+            protocol,
+            host: host.to_ascii_lowercase(),
+            port,
+        }
+    }
+
+    /// Creates the key of the connections that can serve a URL.
+    ///
+    /// # Parameters
+    /// - `url`: The URL, whose port defaults to the one of its protocol.
+    ///
+    /// # Returns
+    /// - `Some`: The key of the URL.
+    /// - `None`: If the URL has no host.
+    pub fn from_url(url: &HttpUrlComponents) -> Option<Self> {
+        let host: &String = url.host.as_ref()?;
+        let port: u16 = url.port.unwrap_or_else(|| url.protocol.get_port());
+        Some(Self::new(url.protocol.clone(), host, port))
+    }
+}
+
+impl<S> Clone for ConnectionPool<S>
+where
+    S: SplitStream,
+{
+    fn clone(&self) -> Self {
+        Self {
+            connections: Arc::clone(&self.connections),
+            permits: Arc::clone(&self.permits),
+            max_connections_per_host: Arc::clone(&self.max_connections_per_host),
+            max_idle_per_host: self.max_idle_per_host,
+            idle_timeout: self.idle_timeout,
+        }
+    }
+}
+
+impl<S> Debug for ConnectionPool<S>
+where
+    S: SplitStream,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConnectionPool")
+            .field(
+                "max_connections_per_host",
+                &self.get_max_connections_per_host(),
+            )
+            .field("max_idle_per_host", &self.max_idle_per_host)
+            .field("idle_timeout", &self.idle_timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S> Default for ConnectionPool<S>
+where
+    S: SplitStream,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> ConnectionPool<S>
+where
+    S: SplitStream,
+{
+    /// Creates an empty `ConnectionPool` with the default limits.
+    ///
+    /// # Returns
+    /// - A new `ConnectionPool` allowing `DEFAULT_POOL_MAX_CONNECTIONS_PER_HOST` connections
+    ///   in use per key, and keeping up to `DEFAULT_POOL_MAX_IDLE_PER_HOST` idle connections
+    ///   per key for `DEFAULT_POOL_IDLE_TIMEOUT_SECS` seconds.
+    pub fn new() -> Self {
+        Self {
+            connections: Arc::new(dash_map_xx_hash3_64()),
+            permits: Arc::new(dash_map_xx_hash3_64()),
+            max_connections_per_host: Arc::new(AtomicUsize::new(
+                DEFAULT_POOL_MAX_CONNECTIONS_PER_HOST,
+            )),
+            max_idle_per_host: DEFAULT_POOL_MAX_IDLE_PER_HOST,
+            idle_timeout: Duration::from_secs(DEFAULT_POOL_IDLE_TIMEOUT_SECS),
+        }
+    }
+
+    /// Retrieves the maximum number of connections in use at once per key.
+    ///
+    /// # Returns
+    /// - `usize`: The maximum number of connections in use.
+    pub fn get_max_connections_per_host(&self) -> usize {
+        self.max_connections_per_host.load(Ordering::Acquire)
+    }
+
+    /// Sets the maximum number of connections in use at once per key.
+    ///
+    /// The limit is shared by every clone of the pool and applies to slots taken
+    /// afterwards, with slots already taken still counted against it. Lowering it
+    /// does not revoke taken slots. Values below `1` are treated as `1`.
+    ///
+    /// # Parameters
+    /// - `max_connections_per_host`: The maximum number of connections in use.
+    ///
+    /// # Returns
+    /// - `&mut Self`: A reference to the pool, allowing for method chaining.
+    pub fn set_max_connections_per_host(&mut self, max_connections_per_host: usize) -> &mut Self {
+        self.max_connections_per_host
+            .store(max_connections_per_host.max(1), Ordering::Release);
+        for slots in self.permits.iter() {
+            slots.notify.notify_waiters();
+        }
+        self
+    }
+
+    /// Retrieves the maximum number of idle connections kept per key.
+    ///
+    /// # Returns
+    /// - `usize`: The maximum number of idle connections.
+    pub fn get_max_idle_per_host(&self) -> usize {
+        self.max_idle_per_host
+    }
+
+    /// Sets the maximum number of idle connections kept per key.
+    ///
+    /// # Parameters
+    /// - `max_idle_per_host`: The maximum number of idle connections, `0` disabling pooling.
+    ///
+    /// # Returns
+    /// - `&mut Self`: A reference to the pool, allowing for method chaining.
+    pub fn set_max_idle_per_host(&mut self, max_idle_per_host: usize) -> &mut Self {
+        self.max_idle_per_host = max_idle_per_host;
+        self
+    }
+
+    /// Retrieves the time an idle connection is kept before it is evicted.
+    ///
+    /// # Returns
+    /// - `Duration`: The idle timeout.
+    pub fn get_idle_timeout(&self) -> Duration {
+        self.idle_timeout
+    }
+
+    /// Sets the time an idle connection is kept before it is evicted.
+    ///
+    /// # Parameters
+    /// - `idle_timeout`: The idle timeout.
+    ///
+    /// # Returns
+    /// - `&mut Self`: A reference to the pool, allowing for method chaining.
+    pub fn set_idle_timeout(&mut self, idle_timeout: Duration) -> &mut Self {
+        self.idle_timeout = idle_timeout;
+        self
+    }
+
+    /// Takes a connection slot of a key, waiting while the key has the maximum
+    /// number of connections in use.
+    ///
+    /// The slot holds the most recently used healthy idle connection, if any.
+    /// Expired connections are evicted, and connections closed by the peer or
+    /// holding unexpected data are dropped.
+    ///
+    /// # Parameters
+    /// - `key`: The key of the connection.
+    ///
+    /// # Returns
+    /// - `Ok`: The slot, to be kept until the connection is checked in or dropped.
+    /// - `Err`: Not returned currently; kept so a checkout can fail in the future.
+    pub async fn checkout(&self, key: &PoolKey) -> Result<PoolCheckout<S>, RequestError> {
+        loop {
+            let notify: Arc<Notify> =
+                Arc::clone(&self.permits.entry(key.clone()).or_default().notify);
+            let notified = notify.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+            {
+                let mut slots: dashmap::mapref::one::RefMut<'_, PoolKey, PoolSlots> =
+                    self.permits.entry(key.clone()).or_default();
+                if slots.in_use < self.get_max_connections_per_host() {
+                    slots.in_use += 1;
+                    break;
+                }
+                if !Arc::ptr_eq(&slots.notify, &notify) {
+                    continue;
+                }
+            }
+            notified.await;
+        }
+        let permit: PoolPermit = PoolPermit {
+            permits: Arc::clone(&self.permits),
+            key: key.clone(),
+        };
+        Ok(PoolCheckout {
+            stream: self.take_idle(key).await,
+            _permit: permit,
+        })
+    }
+
+    /// Retrieves the number of connections of a key in use.
+    ///
+    /// # Parameters
+    /// - `key`: The key of the connections.
+    ///
+    /// # Returns
+    /// - `usize`: The number of slots of the key currently taken.
+    pub fn in_use_count(&self, key: &PoolKey) -> usize {
+        self.permits.get(key).map_or(0, |slots| slots.in_use)
+    }
+
+    /// Takes the most recently used healthy idle connection of a key.
+    ///
+    /// # Parameters
+    /// - `key`: The key of the connection.
+    ///
+    /// # Returns
+    /// - `OptionArcRwLockStream<S>`: A healthy connection, or `None` if there is none.
+    async fn take_idle(&self, key: &PoolKey) -> OptionArcRwLockStream<S> {
+        loop {
+            let pooled: PooledStream<S> = {
+                let mut idle: dashmap::mapref::one::RefMut<'_, PoolKey, Vec<PooledStream<S>>> =
+                    self.connections.get_mut(key)?;
+                idle.pop()?
+            };
+            if pooled.idle_since.elapsed() >= self.idle_timeout {
+                self.evict_expired(key);
+                continue;
+            }
+            if Self::is_healthy(&pooled.stream).await {
+                return Some(pooled.stream);
+            }
+        }
+    }
+
+    /// Returns a connection to the pool once its response has been read completely.
+    ///
+    /// The connection is dropped if the key already holds the maximum number of
+    /// idle connections.
+    ///
+    /// # Parameters
+    /// - `key`: The key of the connection.
+    /// - `stream`: The connection.
+    pub fn checkin(&self, key: PoolKey, stream: ArcRwLockStream<S>) {
+        self.evict_expired(&key);
+        let mut idle: dashmap::mapref::one::RefMut<'_, PoolKey, Vec<PooledStream<S>>> =
+            self.connections.entry(key).or_default();
+        if idle.len() < self.max_idle_per_host {
+            idle.push(PooledStream {
+                stream,
+                idle_since: Instant::now(),
+            });
+        }
+    }
+
+    /// Retrieves the number of idle connections of a key, including expired ones not evicted yet.
+    ///
+    /// # Parameters
+    /// - `key`: The key of the connections.
+    ///
+    /// # Returns
+    /// - `usize`: The number of idle connections.
+    pub fn idle_count(&self, key: &PoolKey) -> usize {
+        self.connections.get(key).map_or(0, |idle| idle.len())
+    }
+
+    /// Evicts every expired idle connection and removes keys without idle connections.
+    pub fn evict_idle(&self) {
+        let idle_timeout: Duration = self.idle_timeout;
+        self.connections.retain(|_, idle| {
+            idle.retain(|pooled| pooled.idle_since.elapsed() < idle_timeout);
+            !idle.is_empty()
+        });
+    }
+
+    /// Evicts the expired idle connections of a key.
+    ///
+    /// # Parameters
+    /// - `key`: The key of the connections.
+    fn evict_expired(&self, key: &PoolKey) {
+        if let Some(mut idle) = self.connections.get_mut(key) {
+            idle.retain(|pooled| pooled.idle_since.elapsed() < self.idle_timeout);
+        }
+    }
+
+    /// Checks whether an idle connection can still carry a request.
+    ///
+    /// The read half is polled once without waiting. A connection is healthy if
+    /// no data is available yet, and unhealthy if the peer closed it, sent data
+    /// nobody asked for, or reading fails.
+    ///
+    /// # Parameters
+    /// - `stream`: The idle connection.
+    ///
+    /// # Returns
+    /// - `bool`: `true` if the connection is healthy; otherwise, `false`.
+    async fn is_healthy(stream: &ArcRwLockStream<S>) -> bool {
+        let mut reader: RwLockWriteGuardReadHalf<'_, S> = stream.read_half().await;
+        tokio::time::timeout(Duration::ZERO, reader.fill_buf())
+            .await
+            .is_err()
+    }
+}
+
+impl<S> PoolCheckout<S>
+where
+    S: SplitStream,
+{
+    /// Retrieves the idle connection taken with the slot.
+    ///
+    /// # Returns
+    /// - `&OptionArcRwLockStream<S>`: The idle connection, or `None` if a new one must be opened.
+    pub fn get_stream(&self) -> &OptionArcRwLockStream<S> {
+        &self.stream
+    }
+
+    /// Takes the idle connection out of the slot, leaving the slot taken.
+    ///
+    /// # Returns
+    /// - `OptionArcRwLockStream<S>`: The idle connection, or `None` if a new one must be opened.
+    pub fn take_stream(&mut self) -> OptionArcRwLockStream<S> {
+        self.stream.take()
+    }
+}
+
+impl Drop for PoolPermit {
+    fn drop(&mut self) {
+        self.permits.remove_if_mut(&self.key, |_, slots| {
+            slots.in_use = slots.in_use.saturating_sub(1);
+            if slots.in_use > 0 {
+                slots.notify.notify_one();
+                return false;
+            }
+            slots.notify.notify_waiters();
+            true
+        });
+    }
+}
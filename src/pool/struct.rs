@@ -0,0 +1,72 @@
+use crate::*;
+
+/// Identifies the connections of a pool that can serve a request.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Getter, DisplayDebug)]
+pub struct PoolKey {
+    /// The protocol of the connections.
+    pub(super) protocol: Protocol,
+    /// The host the connections are made to, lowercased.
+    pub(super) host: String,
+    /// The port the connections are made to.
+    pub(super) port: u16,
+}
+
+/// An idle connection kept by a `ConnectionPool`.
+pub struct PooledStream<S: SplitStream = TcpStream> {
+    /// The connection.
+    pub(super) stream: ArcRwLockStream<S>,
+    /// The moment the connection was returned to the pool.
+    pub(super) idle_since: Instant,
+}
+
+/// A pool of idle keep-alive connections, keyed by protocol, host and port.
+///
+/// Connections are checked out before a request and checked in once its response
+/// has been read completely, so an idle connection never holds unread data. Per key,
+/// at most `max_connections_per_host` connections are in use at once and at most
+/// `max_idle_per_host` are kept idle.
+///
+/// Clones share the idle connections, the connections in use and
+/// `max_connections_per_host`, so a limit set on one clone applies to all of them.
+/// `max_idle_per_host` and `idle_timeout` only apply to the clone they are set on.
+pub struct ConnectionPool<S: SplitStream = TcpStream> {
+    /// The idle connections of every key.
+    pub(super) connections: Arc<PoolConnections<S>>,
+    /// The connections in use of every key.
+    pub(super) permits: Arc<PoolPermits>,
+    /// The maximum number of connections in use at once per key, shared by every clone.
+    pub(super) max_connections_per_host: Arc<AtomicUsize>,
+    /// The maximum number of idle connections kept per key.
+    pub(super) max_idle_per_host: usize,
+    /// The time an idle connection is kept before it is evicted.
+    pub(super) idle_timeout: Duration,
+}
+
+/// A connection slot of a key taken from a `ConnectionPool`.
+///
+/// The slot counts towards the connections in use of its key until it is dropped.
+pub struct PoolCheckout<S: SplitStream = TcpStream> {
+    /// The most recently used healthy idle connection, or `None` if a new one must be opened.
+    pub(super) stream: OptionArcRwLockStream<S>,
+    /// The permit holding the slot, released when the checkout is dropped.
+    pub(super) _permit: PoolPermit,
+}
+
+/// The connections in use of a key of a `ConnectionPool`.
+#[derive(Debug, Default)]
+pub struct PoolSlots {
+    /// The number of connections in use.
+    pub(super) in_use: usize,
+    /// Wakes the checkouts waiting for a slot of the key.
+    pub(super) notify: Arc<Notify>,
+}
+
+/// A connection slot of a key counted as in use until it is dropped.
+///
+/// Dropping the last slot of a key removes the key from the pool's slot accounting.
+pub struct PoolPermit {
+    /// The connections in use of every key of the pool.
+    pub(super) permits: Arc<PoolPermits>,
+    /// The key of the slot.
+    pub(super) key: PoolKey,
+}
@@ -0,0 +1,6 @@
+use crate::*;
+
+/// The idle connections of a pool, keyed by protocol, host and port.
+pub type PoolConnections<S = TcpStream> = DashMapXxHash3_64<PoolKey, Vec<PooledStream<S>>>;
+/// The connections in use, keyed by protocol, host and port.
+pub type PoolPermits = DashMapXxHash3_64<PoolKey, PoolSlots>;
@@ -1,5 +1,5 @@
 /// Defines the `Protocol` enum, representing HTTP-related protocols.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Protocol {
     /// Represents the HTTP protocol.
     HTTP,
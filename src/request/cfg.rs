@@ -0,0 +1,435 @@
+#[tokio::test]
+async fn test_http_from_reader_chunked() {
+    use crate::*;
+    use tokio::net::TcpListener;
+    let listener: TcpListener = TcpListener::bind(SOCKET_ADDR_127_0_0_1).await.unwrap();
+    let mut client: TcpStream = TcpStream::connect(listener.local_addr().unwrap())
+        .await
+        .unwrap();
+    client
+        .write_all(
+            b"POST /upload HTTP/1.1\r\nHost: localhost\r\nTransfer-Encoding: gzip, chunked\r\n\r\n\
+            5;name=value\r\nhello\r\n6\r\n world\r\n0\r\nExpires: never\r\n\r\n",
+        )
+        .await
+        .unwrap();
+    let (mut server, _) = listener.accept().await.unwrap();
+    let mut reader: BufReader<&mut TcpStream> = BufReader::new(&mut server);
+    let request: Request = Request::http_from_reader(&mut reader, &RequestConfig::default())
+        .await
+        .unwrap();
+    assert!(request.is_chunked());
+    assert_eq!(request.get_body_string(), "hello world");
+    assert_eq!(request.get_trailer(EXPIRES), Some("never".to_string()));
+}
+
+#[tokio::test]
+async fn test_http_from_reader_invalid_chunk_size() {
+    use crate::*;
+    use tokio::net::TcpListener;
+    let listener: TcpListener = TcpListener::bind(SOCKET_ADDR_127_0_0_1).await.unwrap();
+    let mut client: TcpStream = TcpStream::connect(listener.local_addr().unwrap())
+        .await
+        .unwrap();
+    client
+        .write_all(b"POST / HTTP/1.1\r\nHost: localhost\r\nTransfer-Encoding: chunked\r\n\r\nzz\r\nhello\r\n0\r\n\r\n")
+        .await
+        .unwrap();
+    let (mut server, _) = listener.accept().await.unwrap();
+    let mut reader: BufReader<&mut TcpStream> = BufReader::new(&mut server);
+    let result: RequestReaderHandleResult =
+        Request::http_from_reader(&mut reader, &RequestConfig::default()).await;
+    assert_eq!(
+        result.unwrap_err(),
+        RequestError::InvalidChunkSize("zz".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_http_from_reader_limits() {
+    use crate::*;
+    use tokio::net::TcpListener;
+    let listener: TcpListener = TcpListener::bind(SOCKET_ADDR_127_0_0_1).await.unwrap();
+    let mut config: RequestConfig = RequestConfig::default();
+    config.set_max_header_count(2).set_max_body_size(4);
+    let requests: [(&[u8], RequestError); 2] = [
+        (
+            b"GET / HTTP/1.1\r\nHost: localhost\r\nAccept: */*\r\nAccept-Language: *\r\n\r\n",
+            RequestError::TooManyHeaders(2),
+        ),
+        (
+            b"POST / HTTP/1.1\r\nHost: localhost\r\nContent-Length: 5\r\n\r\nhello",
+            RequestError::PayloadTooLarge(4),
+        ),
+    ];
+    for (data, error) in requests {
+        let mut client: TcpStream = TcpStream::connect(listener.local_addr().unwrap())
+            .await
+            .unwrap();
+        client.write_all(data).await.unwrap();
+        let (mut server, _) = listener.accept().await.unwrap();
+        let mut reader: BufReader<&mut TcpStream> = BufReader::new(&mut server);
+        let result: RequestReaderHandleResult =
+            Request::http_from_reader(&mut reader, &config).await;
+        assert_eq!(result.unwrap_err(), error);
+    }
+    assert_eq!(
+        RequestError::RequestLineTooLong(DEFAULT_MAX_REQUEST_LINE_LENGTH).get_http_status(),
+        HttpStatus::URITooLong
+    );
+}
+
+#[tokio::test]
+async fn test_http_from_reader_querys_and_form() {
+    use crate::*;
+    use std::collections::HashMap;
+    use tokio::net::TcpListener;
+    let listener: TcpListener = TcpListener::bind(SOCKET_ADDR_127_0_0_1).await.unwrap();
+    let mut client: TcpStream = TcpStream::connect(listener.local_addr().unwrap())
+        .await
+        .unwrap();
+    client
+        .write_all(
+            b"POST /search?q=hello+world%21&tag=a&tag=b%20c&=skip#top HTTP/1.1\r\n\
+            Host: localhost\r\nContent-Length: 18\r\n\r\nname=J%C3%BCrgen+K",
+        )
+        .await
+        .unwrap();
+    let (mut server, _) = listener.accept().await.unwrap();
+    let mut reader: BufReader<&mut TcpStream> = BufReader::new(&mut server);
+    let request: Request = Request::http_from_reader(&mut reader, &RequestConfig::default())
+        .await
+        .unwrap();
+    assert_eq!(request.get_query("q"), Some("hello world!".to_string()));
+    assert_eq!(request.get_query_all("tag"), vec!["a", "b c"]);
+    assert_eq!(request.get_querys().len(), 2);
+    let pairs: Vec<(String, String)> = request.get_querys_as().unwrap();
+    assert_eq!(pairs.len(), 4);
+    let form: HashMap<String, String> = request.get_body_form().unwrap();
+    assert_eq!(form.get("name"), Some(&"Jürgen K".to_string()));
+}
+
+#[tokio::test]
+async fn test_http_from_reader_path_normalization() {
+    use crate::*;
+    use tokio::net::TcpListener;
+    let listener: TcpListener = TcpListener::bind(SOCKET_ADDR_127_0_0_1).await.unwrap();
+    let targets: [(&str, Result<&str, RequestError>); 6] = [
+        ("/a%20b/./c//d/", Ok("/a b/c/d/")),
+        ("/a%+1b", Ok("/a%+1b")),
+        ("/static/%2e%2e/%2E%2E/etc/passwd?x=1", Ok("/etc/passwd")),
+        ("/a/b/..", Ok("/a/")),
+        ("*", Ok("*")),
+        (
+            "/file%00.txt",
+            Err(RequestError::InvalidPath("/file%00.txt".to_string())),
+        ),
+    ];
+    for (target, expected) in targets {
+        let mut client: TcpStream = TcpStream::connect(listener.local_addr().unwrap())
+            .await
+            .unwrap();
+        client
+            .write_all(format!("GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", target).as_bytes())
+            .await
+            .unwrap();
+        let (mut server, _) = listener.accept().await.unwrap();
+        let mut reader: BufReader<&mut TcpStream> = BufReader::new(&mut server);
+        let result: RequestReaderHandleResult =
+            Request::http_from_reader(&mut reader, &RequestConfig::default()).await;
+        assert_eq!(
+            result.map(|request| request.get_normalized_path().clone()),
+            expected.map(str::to_string)
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_http_from_reader_target_forms() {
+    use crate::*;
+    use tokio::net::TcpListener;
+    let listener: TcpListener = TcpListener::bind(SOCKET_ADDR_127_0_0_1).await.unwrap();
+    let requests: [(&str, RequestTargetForm, &str, &str); 3] = [
+        (
+            "GET http://example.com:8080/a/../b?x=1 HTTP/1.1\r\nHost: proxy\r\n\r\n",
+            RequestTargetForm::Absolute,
+            "example.com:8080",
+            "/b",
+        ),
+        (
+            "CONNECT example.com:443 HTTP/1.1\r\nHost: example.com:443\r\n\r\n",
+            RequestTargetForm::Authority,
+            "example.com:443",
+            "",
+        ),
+        (
+            "OPTIONS * HTTP/1.1\r\nHost: localhost\r\n\r\n",
+            RequestTargetForm::Asterisk,
+            "localhost",
+            "*",
+        ),
+    ];
+    for (data, target_form, host, path) in requests {
+        let mut client: TcpStream = TcpStream::connect(listener.local_addr().unwrap())
+            .await
+            .unwrap();
+        client.write_all(data.as_bytes()).await.unwrap();
+        let (mut server, _) = listener.accept().await.unwrap();
+        let mut reader: BufReader<&mut TcpStream> = BufReader::new(&mut server);
+        let request: Request = Request::http_from_reader(&mut reader, &RequestConfig::default())
+            .await
+            .unwrap();
+        assert_eq!(request.get_target_form(), &target_form);
+        assert_eq!(request.get_host(), host);
+        assert_eq!(request.get_normalized_path(), path);
+    }
+}
+
+#[tokio::test]
+async fn test_http_from_reader_host_validation() {
+    use crate::*;
+    use tokio::net::TcpListener;
+    let listener: TcpListener = TcpListener::bind(SOCKET_ADDR_127_0_0_1).await.unwrap();
+    let requests: [(&str, RequestError); 3] = [
+        ("GET / HTTP/1.1\r\n\r\n", RequestError::MissingHost),
+        (
+            "GET / HTTP/1.1\r\nHost: a.com\r\nHost: b.com\r\n\r\n",
+            RequestError::DuplicateHost,
+        ),
+        (
+            "GET / HTTP/1.0\r\nHost: a.com:http\r\n\r\n",
+            RequestError::InvalidHost("a.com:http".to_string()),
+        ),
+    ];
+    for (data, error) in requests {
+        let mut client: TcpStream = TcpStream::connect(listener.local_addr().unwrap())
+            .await
+            .unwrap();
+        client.write_all(data.as_bytes()).await.unwrap();
+        let (mut server, _) = listener.accept().await.unwrap();
+        let mut reader: BufReader<&mut TcpStream> = BufReader::new(&mut server);
+        let result: RequestReaderHandleResult =
+            Request::http_from_reader(&mut reader, &RequestConfig::default()).await;
+        assert_eq!(result.unwrap_err(), error);
+    }
+    let mut client: TcpStream = TcpStream::connect(listener.local_addr().unwrap())
+        .await
+        .unwrap();
+    client
+        .write_all(b"GET / HTTP/1.1\r\nHost: [::1]:8080\r\n\r\n")
+        .await
+        .unwrap();
+    let (mut server, _) = listener.accept().await.unwrap();
+    let mut reader: BufReader<&mut TcpStream> = BufReader::new(&mut server);
+    let request: Request = Request::http_from_reader(&mut reader, &RequestConfig::default())
+        .await
+        .unwrap();
+    assert_eq!(
+        request.get_host_name(),
+        Some(&AuthorityHost::Ipv6(Ipv6Addr::LOCALHOST))
+    );
+    assert_eq!(request.get_port(), Some(8080));
+}
+
+#[tokio::test]
+async fn test_http_from_reader_truncated() {
+    use crate::*;
+    use tokio::net::TcpListener;
+    let listener: TcpListener = TcpListener::bind(SOCKET_ADDR_127_0_0_1).await.unwrap();
+    let requests: [(&str, RequestError); 4] = [
+        ("", RequestError::ClientDisconnected),
+        (
+            "GET / HTTP/1.1\r\nHost: localhost\r\n",
+            RequestError::ClientDisconnected,
+        ),
+        (
+            "POST / HTTP/1.1\r\nHost: localhost\r\nContent-Length: 10\r\n\r\nabc",
+            RequestError::IncompleteBody {
+                expected: 10,
+                received: 3,
+            },
+        ),
+        (
+            "POST / HTTP/1.1\r\nHost: localhost\r\nTransfer-Encoding: chunked\r\n\r\n2\r\nab\r\n5\r\nc",
+            RequestError::IncompleteBody {
+                expected: 7,
+                received: 3,
+            },
+        ),
+    ];
+    for (data, error) in requests {
+        let mut client: TcpStream = TcpStream::connect(listener.local_addr().unwrap())
+            .await
+            .unwrap();
+        client.write_all(data.as_bytes()).await.unwrap();
+        drop(client);
+        let (mut server, _) = listener.accept().await.unwrap();
+        let mut reader: BufReader<&mut TcpStream> = BufReader::new(&mut server);
+        let result: RequestReaderHandleResult =
+            Request::http_from_reader(&mut reader, &RequestConfig::default()).await;
+        assert_eq!(result.unwrap_err(), error);
+    }
+    assert_eq!(
+        RequestError::from_io_error(std::io::Error::from(ErrorKind::ConnectionReset)),
+        RequestError::ConnectionReset
+    );
+}
+
+#[test]
+fn test_request_head_parse() {
+    use crate::*;
+    let config: RequestConfig = RequestConfig::default();
+    let buffer: &[u8] =
+        b"GET /a/../b?x=1 HTTP/1.1\r\nHost: Example.com:8080\r\nX-Raw:  \xff value \r\nno colon\r\n\r\nbody";
+    let (head, consumed) = RequestHead::parse(buffer, &config).unwrap().unwrap();
+    assert_eq!(&buffer[consumed..], b"body");
+    assert_eq!(head.get_method(), "GET");
+    assert_eq!(head.get_target(), "/a/../b?x=1");
+    assert_eq!(head.get_version(), "HTTP/1.1");
+    assert_eq!(head.get_headers().len(), 2);
+    assert_eq!(head.get_header("x-raw"), Some(&b"\xff value"[..]));
+    let request: Request = Request::from_head(&head).unwrap();
+    assert_eq!(request.get_path(), "/a/../b");
+    assert_eq!(request.get_normalized_path(), "/b");
+    assert_eq!(request.get_query("x"), Some("1".to_string()));
+    assert_eq!(request.get_port(), Some(8080));
+    assert_eq!(
+        request.get_header("x-raw"),
+        Some("\u{fffd} value".to_string())
+    );
+    assert_eq!(RequestHead::parse(&buffer[..30], &config), Ok(None));
+    assert_eq!(
+        RequestHead::parse(b"GET /\r\n\r\n", &config),
+        Err(RequestError::InvalidHttpRequestPartsLength(2))
+    );
+    let mut config: RequestConfig = RequestConfig::default();
+    config.set_max_request_line_length(8);
+    assert_eq!(
+        RequestHead::parse(b"GET /long", &config),
+        Err(RequestError::RequestLineTooLong(8))
+    );
+}
+
+#[tokio::test]
+async fn test_request_builder_round_trip() {
+    use crate::*;
+    let request: Request = RequestBuilder::new()
+        .set_method(Method::POST)
+        .set_path("/search")
+        .append_query("q", "a b&c")
+        .append_query("q", "d")
+        .set_header(HOST, "localhost:8080")
+        .append_header(COOKIE, "a=1")
+        .set_body("hello")
+        .build()
+        .unwrap();
+    assert_eq!(request.get_target(), "/search?q=a+b%26c&q=d");
+    assert_eq!(request.get_query_all("q"), vec!["a b&c", "d"]);
+    assert_eq!(request.get_cookie("a"), Some("1".to_string()));
+    let data: RequestData = request.build();
+    assert_eq!(
+        data,
+        b"POST /search?q=a+b%26c&q=d HTTP/1.1\r\nhost: localhost:8080\r\ncookie: a=1\r\ncontent-length: 5\r\n\r\nhello"
+    );
+    let mut reader: BufReader<&[u8]> = BufReader::new(&data[..]);
+    let parsed: Request = Request::http_from_reader(&mut reader, &RequestConfig::default())
+        .await
+        .unwrap();
+    assert_eq!(parsed.get_body(), b"hello");
+    assert_eq!(parsed.get_port(), Some(8080));
+    let chunked: RequestData = RequestBuilder::new()
+        .set_header(HOST, "localhost")
+        .set_header(TRANSFER_ENCODING, CHUNKED)
+        .set_body("hi")
+        .build()
+        .unwrap()
+        .build();
+    assert!(chunked.ends_with(b"\r\n\r\n2\r\nhi\r\n0\r\n\r\n"));
+    assert_eq!(
+        RequestBuilder::new()
+            .set_header(HOST, "localhost")
+            .set_path("/a b")
+            .build()
+            .unwrap_err(),
+        RequestError::InvalidPath("/a b".to_string())
+    );
+    assert_eq!(
+        RequestBuilder::new().build().unwrap_err(),
+        RequestError::MissingHost
+    );
+}
+
+#[tokio::test]
+async fn test_http_request_expect_continue() {
+    use crate::*;
+    let head: &[u8] =
+        b"PUT /upload HTTP/1.1\r\nHost: localhost\r\nExpect: 100-Continue\r\nContent-Length: 4\r\n\r\n";
+    let (mut client, server) = tokio::io::duplex(4096);
+    let stream: ArcRwLockStream<DuplexStream> = ArcRwLockStream::from_stream(server);
+    client.write_all(head).await.unwrap();
+    let mut config: RequestConfig = RequestConfig::default();
+    let reading: tokio::task::JoinHandle<RequestReaderHandleResult> = tokio::spawn({
+        let stream: ArcRwLockStream<DuplexStream> = stream.clone();
+        async move { Request::http_request_from_stream(&stream, &config).await }
+    });
+    let mut interim: Vec<u8> = vec![0; 25];
+    client.read_exact(&mut interim).await.unwrap();
+    assert_eq!(interim, b"HTTP/1.1 100 Continue\r\n\r\n");
+    client.write_all(b"data").await.unwrap();
+    assert_eq!(reading.await.unwrap().unwrap().get_body(), b"data");
+    config.set_expect_continue(ExpectContinue::Manual);
+    client.write_all(head).await.unwrap();
+    let mut request: Request = Request::http_request_from_stream(&stream, &config)
+        .await
+        .unwrap();
+    assert!(request.is_expect_continue());
+    assert!(request.get_body().is_empty());
+    client.write_all(b"next").await.unwrap();
+    request.accept_continue(&stream, &config).await.unwrap();
+    assert_eq!(request.get_body(), b"next");
+    client.read_exact(&mut interim).await.unwrap();
+    assert_eq!(interim, b"HTTP/1.1 100 Continue\r\n\r\n");
+    client.write_all(head).await.unwrap();
+    let request: Request = Request::http_request_from_stream(&stream, &config)
+        .await
+        .unwrap();
+    request.reject_continue(&stream).await.unwrap();
+    let mut reader: BufReader<&mut DuplexStream> = BufReader::new(&mut client);
+    let response: Response =
+        Response::http_from_reader(&mut reader, &RequestConfig::default(), &Method::PUT)
+            .await
+            .unwrap();
+    assert_eq!(response.get_status_code(), &417);
+    assert_eq!(response.get_header(CONNECTION), Some(CLOSE.to_string()));
+    let (mut client, server) = tokio::io::duplex(4096);
+    let stream: ArcRwLockStream<DuplexStream> = ArcRwLockStream::from_stream(server);
+    client
+        .write_all(b"GET / HTTP/1.1\r\nHost: localhost\r\nExpect: later\r\n\r\n")
+        .await
+        .unwrap();
+    let err: RequestError = Request::http_request_from_stream(&stream, &config)
+        .await
+        .unwrap_err();
+    assert_eq!(err, RequestError::ExpectationFailed("later".to_string()));
+    assert_eq!(err.get_http_status(), HttpStatus::ExpectationFailed);
+    let mut reader: BufReader<&[u8]> = BufReader::new(head);
+    let request: Request = Request::http_from_reader(&mut reader, &config)
+        .await
+        .unwrap();
+    assert!(request.get_body().is_empty());
+    let (mut client, server) = tokio::io::duplex(4096);
+    let stream: ArcRwLockStream<DuplexStream> = ArcRwLockStream::from_stream(server);
+    client.write_all(head).await.unwrap();
+    let mut config: RequestConfig = RequestConfig::default();
+    config.set_max_body_size(2);
+    assert_eq!(
+        Request::http_request_from_stream(&stream, &config)
+            .await
+            .unwrap_err(),
+        RequestError::PayloadTooLarge(2)
+    );
+    drop(stream);
+    let mut rest: Vec<u8> = Vec::new();
+    client.read_to_end(&mut rest).await.unwrap();
+    assert!(rest.is_empty());
+}
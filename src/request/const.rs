@@ -0,0 +1,23 @@
+/// Separator between the items of a comma-separated header list.
+pub(crate) const COMMA: &str = ",";
+
+/// Default maximum length of the request line in bytes.
+pub const DEFAULT_MAX_REQUEST_LINE_LENGTH: usize = 8192;
+
+/// Default maximum total length of all header lines in bytes.
+pub const DEFAULT_MAX_HEADER_BYTES: usize = 65536;
+
+/// Default maximum number of header lines.
+pub const DEFAULT_MAX_HEADER_COUNT: usize = 100;
+
+/// Default maximum size of the decoded request body in bytes.
+pub const DEFAULT_MAX_BODY_SIZE: usize = 67108864;
+
+/// The request target of server-wide `OPTIONS` requests.
+pub const ASTERISK_TARGET: &str = "*";
+
+/// Separates the scheme from the rest of an absolute URI.
+pub(crate) const SCHEME_SEPARATOR: &str = "://";
+
+/// The `Expect` header value asking for a `100 Continue` interim response.
+pub const EXPECT_100_CONTINUE: &str = "100-continue";
@@ -0,0 +1,24 @@
+/// The form of the request target in the request line, as defined by RFC 9112.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RequestTargetForm {
+    /// An absolute path with an optional query, e.g. `/index.html?a=1`.
+    #[default]
+    Origin,
+    /// An absolute URI as sent to proxies, e.g. `http://example.com/index.html`.
+    Absolute,
+    /// A host and port as sent with `CONNECT`, e.g. `example.com:443`.
+    Authority,
+    /// A single asterisk as sent with server-wide `OPTIONS`.
+    Asterisk,
+}
+
+/// How a request expecting `100 Continue` is handled while it is read.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExpectContinue {
+    /// A `100 Continue` interim response is sent through the stream before the body is read.
+    #[default]
+    Auto,
+    /// The body is left unread, so the caller can accept the request with
+    /// `Request::accept_continue` or reject it with `Request::reject_continue`.
+    Manual,
+}
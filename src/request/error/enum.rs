@@ -3,27 +3,46 @@ use crate::*;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum RequestError {
     HttpRead,
+    MissingHost,
+    DuplicateHost,
     GetTcpStream,
     GetTlsStream,
     ReadConnection,
     RequestAborted,
+    RequestTimeout,
     TlsStreamConnect,
     NeedOpenRedirect,
     MaxRedirectTimes,
     MethodsNotSupport,
     RedirectInvalidUrl,
     ClientDisconnected,
+    ConnectionReset,
     RedirectUrlDeadLoop,
     ClientClosedConnection,
+    InvalidChunkTerminator,
     IncompleteWebSocketFrame,
+    Io(String),
     Request(String),
     Unknown(String),
     InvalidUrl(String),
+    InvalidPath(String),
+    InvalidHost(String),
+    InvalidChunkSize(String),
+    InvalidStatusLine(String),
+    InvalidContentLength(String),
+    InvalidTransferEncoding(String),
+    ExpectationFailed(String),
     SetReadTimeout(String),
     SetWriteTimeout(String),
     TcpStreamConnect(String),
     TlsConnectorBuild(String),
+    TlsHandshake(String),
     InvalidWebSocketFrame(String),
     InvalidWebSocketRequest(String),
+    TooManyHeaders(usize),
+    HeadersTooLarge(usize),
+    PayloadTooLarge(usize),
+    RequestLineTooLong(usize),
     InvalidHttpRequestPartsLength(usize),
+    IncompleteBody { expected: usize, received: usize },
 }
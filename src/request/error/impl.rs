@@ -6,27 +6,52 @@ impl Display for RequestError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::HttpRead => write!(f, "Http read error"),
+            Self::MissingHost => write!(f, "Missing host header"),
+            Self::DuplicateHost => write!(f, "Duplicate host header"),
             Self::GetTcpStream => write!(f, "Failed to get tcp stream"),
             Self::GetTlsStream => write!(f, "Failed to get tls stream"),
             Self::ReadConnection => write!(f, "Connection read error"),
             Self::RequestAborted => write!(f, "Request aborted"),
+            Self::RequestTimeout => write!(f, "Request timed out"),
             Self::TlsStreamConnect => write!(f, "Tls stream connection error"),
             Self::NeedOpenRedirect => write!(f, "Open redirect required"),
             Self::MaxRedirectTimes => write!(f, "Exceeded maximum redirect attempts"),
             Self::MethodsNotSupport => write!(f, "Http method not supported"),
             Self::RedirectInvalidUrl => write!(f, "Invalid redirect url"),
             Self::ClientDisconnected => write!(f, "Client disconnected"),
+            Self::ConnectionReset => write!(f, "Connection reset by peer"),
             Self::RedirectUrlDeadLoop => write!(f, "Redirect url dead loop detected"),
             Self::ClientClosedConnection => {
                 write!(f, "Client closed connection")
             }
+            Self::InvalidChunkTerminator => {
+                write!(f, "Chunk data is not terminated by CRLF")
+            }
             Self::IncompleteWebSocketFrame => write!(
                 f,
                 "WebSocket connection closed before a complete frame was received"
             ),
+            Self::Io(err) => write!(f, "I/O error{}{}", COLON_SPACE, err),
             Self::Request(err) => write!(f, "Request error{}{}", COLON_SPACE, err),
             Self::Unknown(err) => write!(f, "Unknown error{}{}", COLON_SPACE, err),
             Self::InvalidUrl(err) => write!(f, "Invalid url{}{}", COLON_SPACE, err),
+            Self::InvalidPath(path) => write!(f, "Invalid path{}{}", COLON_SPACE, path),
+            Self::InvalidHost(host) => write!(f, "Invalid host{}{}", COLON_SPACE, host),
+            Self::InvalidChunkSize(size) => {
+                write!(f, "Invalid chunk size{}{}", COLON_SPACE, size)
+            }
+            Self::InvalidStatusLine(line) => {
+                write!(f, "Invalid status line{}{}", COLON_SPACE, line)
+            }
+            Self::InvalidContentLength(length) => {
+                write!(f, "Invalid content length{}{}", COLON_SPACE, length)
+            }
+            Self::InvalidTransferEncoding(coding) => {
+                write!(f, "Invalid transfer encoding{}{}", COLON_SPACE, coding)
+            }
+            Self::ExpectationFailed(expect) => {
+                write!(f, "Unsupported expectation{}{}", COLON_SPACE, expect)
+            }
             Self::SetReadTimeout(err) => {
                 write!(f, "Failed to set read timeout{}{}", COLON_SPACE, err)
             }
@@ -39,6 +64,9 @@ impl Display for RequestError {
             Self::TlsConnectorBuild(err) => {
                 write!(f, "Tls connector build error{}{}", COLON_SPACE, err)
             }
+            Self::TlsHandshake(err) => {
+                write!(f, "Tls handshake error{}{}", COLON_SPACE, err)
+            }
             Self::InvalidWebSocketFrame(err) => {
                 write!(f, "Invalid websocket frame{}{}", COLON_SPACE, err)
             }
@@ -46,9 +74,83 @@ impl Display for RequestError {
             Self::InvalidWebSocketRequest(err) => {
                 write!(f, "Invalid websocket request{}{}", COLON_SPACE, err)
             }
+            Self::TooManyHeaders(max) => {
+                write!(f, "Too many headers, the limit is{}{}", COLON_SPACE, max)
+            }
+            Self::HeadersTooLarge(max) => {
+                write!(
+                    f,
+                    "Headers too large, the limit in bytes is{}{}",
+                    COLON_SPACE, max
+                )
+            }
+            Self::PayloadTooLarge(max) => {
+                write!(
+                    f,
+                    "Payload too large, the limit in bytes is{}{}",
+                    COLON_SPACE, max
+                )
+            }
+            Self::RequestLineTooLong(max) => {
+                write!(
+                    f,
+                    "Request line too long, the limit in bytes is{}{}",
+                    COLON_SPACE, max
+                )
+            }
             Self::InvalidHttpRequestPartsLength(len) => {
                 write!(f, "Invalid http request parts length{}{}", COLON_SPACE, len)
             }
+            Self::IncompleteBody { expected, received } => write!(
+                f,
+                "Incomplete body, expected {} bytes but received{}{}",
+                expected, COLON_SPACE, received
+            ),
+        }
+    }
+}
+
+impl RequestError {
+    /// Maps an I/O error raised while reading a request to the matching variant.
+    ///
+    /// # Parameters
+    /// - `err`: The I/O error.
+    ///
+    /// # Returns
+    /// - `RequestError::ClientDisconnected` if the stream ended early.
+    /// - `RequestError::ConnectionReset` if the peer reset or aborted the connection.
+    /// - `RequestError::RequestTimeout` if the read timed out.
+    /// - `RequestError::Io` for every other error.
+    pub(crate) fn from_io_error(err: std::io::Error) -> Self {
+        match err.kind() {
+            ErrorKind::UnexpectedEof => Self::ClientDisconnected,
+            ErrorKind::ConnectionReset | ErrorKind::ConnectionAborted | ErrorKind::BrokenPipe => {
+                Self::ConnectionReset
+            }
+            ErrorKind::TimedOut => Self::RequestTimeout,
+            _ => Self::Io(err.to_string()),
+        }
+    }
+
+    /// Returns the HTTP status that a server should answer this error with.
+    ///
+    /// # Returns
+    /// - `HttpStatus::RequestTimeout` (408) if the request was not received in time.
+    /// - `HttpStatus::PayloadTooLarge` (413) if the body exceeds its limit.
+    /// - `HttpStatus::URITooLong` (414) if the request line exceeds its limit.
+    /// - `HttpStatus::ExpectationFailed` (417) if the `Expect` header cannot be met.
+    /// - `HttpStatus::RequestHeaderFieldsTooLarge` (431) if the header count or size exceeds its limit.
+    /// - `HttpStatus::BadRequest` (400) for every other error.
+    pub fn get_http_status(&self) -> HttpStatus {
+        match self {
+            Self::RequestTimeout => HttpStatus::RequestTimeout,
+            Self::PayloadTooLarge(_) => HttpStatus::PayloadTooLarge,
+            Self::RequestLineTooLong(_) => HttpStatus::URITooLong,
+            Self::ExpectationFailed(_) => HttpStatus::ExpectationFailed,
+            Self::TooManyHeaders(_) | Self::HeadersTooLarge(_) => {
+                HttpStatus::RequestHeaderFieldsTooLarge
+            }
+            _ => HttpStatus::BadRequest,
         }
     }
 }
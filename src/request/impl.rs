@@ -5,141 +5,1153 @@ impl Default for Request {
         Self {
             method: Method::default(),
             host: String::new(),
+            authority: None,
             version: HttpVersion::default(),
+            target: String::new(),
+            target_form: RequestTargetForm::default(),
             path: String::new(),
+            normalized_path: String::new(),
+            query_string: String::new(),
             querys: hash_map_xx_hash3_64(),
-            headers: hash_map_xx_hash3_64(),
+            headers: HeaderMap::new(),
+            trailers: HeaderMap::new(),
+            cookies: hash_map_xx_hash3_64(),
             body: Vec::new(),
         }
     }
 }
 
+impl Default for RequestConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestConfig {
+    /// Creates a new `RequestConfig` with the default buffer size and limits.
+    ///
+    /// # Returns
+    /// - An initialized `RequestConfig` with default values.
+    pub fn new() -> Self {
+        Self {
+            buffer_size: DEFAULT_BUFFER_SIZE,
+            max_request_line_length: DEFAULT_MAX_REQUEST_LINE_LENGTH,
+            max_header_bytes: DEFAULT_MAX_HEADER_BYTES,
+            max_header_count: DEFAULT_MAX_HEADER_COUNT,
+            max_body_size: DEFAULT_MAX_BODY_SIZE,
+            expect_continue: ExpectContinue::Auto,
+        }
+    }
+}
+
+impl RequestTargetForm {
+    /// Determines the form of a request target.
+    ///
+    /// Targets of `CONNECT` requests are in authority-form. Targets containing a
+    /// scheme are in absolute-form. Every other target is treated as origin-form.
+    ///
+    /// # Parameters
+    /// - `method`: The request method.
+    /// - `target`: The request target from the request line.
+    ///
+    /// # Returns
+    /// - `RequestTargetForm`: The form of the target.
+    pub fn classify(method: &RequestMethod, target: &str) -> Self {
+        if method.is_connect() {
+            Self::Authority
+        } else if target == ASTERISK_TARGET {
+            Self::Asterisk
+        } else if !target.starts_with('/') && target.contains(SCHEME_SEPARATOR) {
+            Self::Absolute
+        } else {
+            Self::Origin
+        }
+    }
+}
+
+impl Default for RequestBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestBuilder {
+    /// Creates a new `RequestBuilder` for an HTTP/1.1 `GET` request to `/`.
+    ///
+    /// # Returns
+    /// - An initialized `RequestBuilder` without headers or body.
+    pub fn new() -> Self {
+        Self {
+            method: Method::GET,
+            version: HttpVersion::HTTP1_1,
+            path: ROOT_PATH.to_string(),
+            querys: Vec::new(),
+            headers: HeaderMap::new(),
+            body: Vec::new(),
+        }
+    }
+
+    /// Sets the HTTP method.
+    ///
+    /// # Parameters
+    /// - `method`: The HTTP method.
+    ///
+    /// # Returns
+    /// - `&mut Self`: A reference to the builder, allowing for method chaining.
+    pub fn set_method(&mut self, method: RequestMethod) -> &mut Self {
+        self.method = method;
+        self
+    }
+
+    /// Sets the HTTP version.
+    ///
+    /// # Parameters
+    /// - `version`: The HTTP version.
+    ///
+    /// # Returns
+    /// - `&mut Self`: A reference to the builder, allowing for method chaining.
+    pub fn set_version(&mut self, version: RequestVersion) -> &mut Self {
+        self.version = version;
+        self
+    }
+
+    /// Sets the request path, which is sent as it is.
+    ///
+    /// # Parameters
+    /// - `path`: The request path, e.g. `/search`.
+    ///
+    /// # Returns
+    /// - `&mut Self`: A reference to the builder, allowing for method chaining.
+    pub fn set_path<T: Into<RequestPath>>(&mut self, path: T) -> &mut Self {
+        self.path = path.into();
+        self
+    }
+
+    /// Appends a query parameter, which is percent-encoded into the target.
+    ///
+    /// # Parameters
+    /// - `key`: The parameter name.
+    /// - `value`: The parameter value.
+    ///
+    /// # Returns
+    /// - `&mut Self`: A reference to the builder, allowing for method chaining.
+    pub fn append_query<K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<RequestQuerysKey>,
+        V: Into<RequestQuerysValue>,
+    {
+        self.querys.push((key.into(), value.into()));
+        self
+    }
+
+    /// Sets a header, replacing every existing value of the same key.
+    ///
+    /// # Parameters
+    /// - `key`: The header key.
+    /// - `value`: The header value.
+    ///
+    /// # Returns
+    /// - `&mut Self`: A reference to the builder, allowing for method chaining.
+    pub fn set_header<K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<RequestHeadersKey>,
+        V: Into<RequestHeadersValue>,
+    {
+        self.headers.insert(key, value);
+        self
+    }
+
+    /// Appends a header, keeping every existing value of the same key.
+    ///
+    /// # Parameters
+    /// - `key`: The header key.
+    /// - `value`: The header value.
+    ///
+    /// # Returns
+    /// - `&mut Self`: A reference to the builder, allowing for method chaining.
+    pub fn append_header<K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<RequestHeadersKey>,
+        V: Into<RequestHeadersValue>,
+    {
+        self.headers.append(key, value);
+        self
+    }
+
+    /// Removes every value of a header.
+    ///
+    /// # Parameters
+    /// - `key`: The header key, matched case-insensitively.
+    ///
+    /// # Returns
+    /// - `&mut Self`: A reference to the builder, allowing for method chaining.
+    pub fn remove_header<K>(&mut self, key: K) -> &mut Self
+    where
+        K: AsRef<str>,
+    {
+        self.headers.remove(key);
+        self
+    }
+
+    /// Sets the body.
+    ///
+    /// # Parameters
+    /// - `body`: The body content.
+    ///
+    /// # Returns
+    /// - `&mut Self`: A reference to the builder, allowing for method chaining.
+    pub fn set_body<T: Into<RequestBody>>(&mut self, body: T) -> &mut Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Builds the request, validating it the same way as a parsed request.
+    ///
+    /// # Returns
+    /// - `Ok`: The `Request`, with its target made of the path and the encoded query,
+    ///   which is appended with `&` if the path already has a query.
+    /// - `Err`: `RequestError::InvalidPath` if the path is empty or contains whitespace,
+    ///   or an `RequestError` if the target or the `Host` header is invalid.
+    pub fn build(&self) -> RequestReaderHandleResult {
+        if self.path.is_empty()
+            || self
+                .path
+                .bytes()
+                .any(|byte| byte.is_ascii_whitespace() || byte.is_ascii_control())
+        {
+            return Err(RequestError::InvalidPath(self.path.clone()));
+        }
+        let mut target: RequestTarget = self.path.clone();
+        if !self.querys.is_empty() {
+            let query: String = url_encoded_to_string(&self.querys)
+                .map_err(|err| RequestError::InvalidUrl(err.to_string()))?;
+            target.push_str(if target.contains(QUERY_SYMBOL) {
+                AND
+            } else {
+                QUERY_SYMBOL
+            });
+            target.push_str(&query);
+        }
+        let mut request: Request = Request::from_parts(
+            &self.method.to_string(),
+            &target,
+            &self.version.to_string(),
+            self.headers.clone(),
+        )?;
+        request.body = self.body.clone();
+        Ok(request)
+    }
+}
+
+impl<'a> RequestHead<'a> {
+    /// Parses the request line and headers at the start of a buffer without copying them.
+    ///
+    /// Line terminators may be CRLF or a bare LF. Header lines without a colon
+    /// count towards the limits but are skipped. The same limits as for reading
+    /// from a stream apply, and are also enforced on an incomplete head.
+    ///
+    /// # Parameters
+    /// - `buffer`: The bytes received so far.
+    /// - `config`: The limits applied while parsing.
+    ///
+    /// # Returns
+    /// - `Ok(Some)`: The head and the number of bytes it occupies, after which the body starts.
+    /// - `Ok(None)`: If the buffer does not hold a complete head yet.
+    /// - `Err`: An `RequestError` if the head is malformed or exceeds a limit.
+    pub fn parse(buffer: &'a [u8], config: &RequestConfig) -> RequestHeadResult<'a> {
+        let line_too_long: RequestError =
+            RequestError::RequestLineTooLong(config.max_request_line_length);
+        let Some(line_end) = buffer.iter().position(|&byte| byte == BR_BYTES[0]) else {
+            if buffer.len() > config.max_request_line_length {
+                return Err(line_too_long);
+            }
+            return Ok(None);
+        };
+        if line_end + 1 > config.max_request_line_length {
+            return Err(line_too_long);
+        }
+        let request_line: &str =
+            std::str::from_utf8(buffer[..line_end].trim_ascii()).map_err(|_| {
+                RequestError::InvalidUrl(String::from_utf8_lossy(&buffer[..line_end]).into_owned())
+            })?;
+        let mut parts: std::str::SplitAsciiWhitespace<'a> = request_line.split_ascii_whitespace();
+        let (Some(method), Some(target), Some(version)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(RequestError::InvalidHttpRequestPartsLength(
+                request_line.split_ascii_whitespace().count(),
+            ));
+        };
+        let mut headers: RequestHeadHeaders<'a> = Vec::new();
+        let mut position: usize = line_end + 1;
+        let mut header_bytes: usize = 0;
+        let mut header_count: usize = 0;
+        loop {
+            let rest: &'a [u8] = &buffer[position..];
+            let Some(header_end) = rest.iter().position(|&byte| byte == BR_BYTES[0]) else {
+                if header_bytes + rest.len() > config.max_header_bytes {
+                    return Err(RequestError::HeadersTooLarge(config.max_header_bytes));
+                }
+                return Ok(None);
+            };
+            header_bytes += header_end + 1;
+            if header_bytes > config.max_header_bytes {
+                return Err(RequestError::HeadersTooLarge(config.max_header_bytes));
+            }
+            position += header_end + 1;
+            let header_line: &'a [u8] = rest[..header_end].trim_ascii();
+            if header_line.is_empty() {
+                break;
+            }
+            header_count += 1;
+            if header_count > config.max_header_count {
+                return Err(RequestError::TooManyHeaders(config.max_header_count));
+            }
+            let Some(colon) = header_line.iter().position(|&byte| byte == b':') else {
+                continue;
+            };
+            if let Ok(key) = std::str::from_utf8(header_line[..colon].trim_ascii()) {
+                headers.push((key, header_line[colon + 1..].trim_ascii()));
+            }
+        }
+        Ok(Some((
+            Self {
+                method,
+                target,
+                version,
+                headers,
+            },
+            position,
+        )))
+    }
+
+    /// Retrieves the method exactly as sent in the request line.
+    ///
+    /// # Returns
+    /// - `&'a str`: The method.
+    pub fn get_method(&self) -> &'a str {
+        self.method
+    }
+
+    /// Retrieves the request target exactly as sent in the request line.
+    ///
+    /// # Returns
+    /// - `&'a str`: The request target.
+    pub fn get_target(&self) -> &'a str {
+        self.target
+    }
+
+    /// Retrieves the version exactly as sent in the request line.
+    ///
+    /// # Returns
+    /// - `&'a str`: The version.
+    pub fn get_version(&self) -> &'a str {
+        self.version
+    }
+
+    /// Retrieves the headers in the order they were sent.
+    ///
+    /// # Returns
+    /// - `&[RequestHeadHeader<'a>]`: The header names and raw values.
+    pub fn get_headers(&self) -> &[RequestHeadHeader<'a>] {
+        &self.headers
+    }
+
+    /// Retrieves the first value of a header.
+    ///
+    /// # Parameters
+    /// - `key`: The header name, matched case-insensitively.
+    ///
+    /// # Returns
+    /// - `Option<&'a [u8]>`: The raw value, or `None` if the header is not present.
+    pub fn get_header(&self, key: &str) -> Option<&'a [u8]> {
+        self.headers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(key))
+            .map(|(_, value)| *value)
+    }
+}
+
 impl Request {
-    /// Creates a new `Request` object from a TCP stream.
+    /// Creates a new `Request` object from a buffered reader.
+    ///
+    /// # Parameters
+    /// - `reader`: A mut reference to a buffered reader, such as a `BufReader<&mut TcpStream>`.
+    /// - `config`: The buffer size and limits applied while reading.
+    ///
+    /// # Returns
+    /// - `Ok`: A `Request` object populated with the HTTP request data. With
+    ///   `ExpectContinue::Manual`, the body of a request expecting `100 Continue`
+    ///   is left unread. Since a reader cannot send the interim response,
+    ///   `ExpectContinue::Auto` only takes effect in `http_request_from_stream`:
+    ///   here the body is read right away, and a client waiting for `100 Continue`
+    ///   stalls until it gives up waiting.
+    /// - `Err`: An `RequestError` if the request is invalid, exceeds a limit or cannot be read.
+    ///   The stream ending before the head is complete yields `RequestError::ClientDisconnected`,
+    ///   and before the body is complete `RequestError::IncompleteBody`.
+    pub async fn http_from_reader<R>(
+        reader: &mut R,
+        config: &RequestConfig,
+    ) -> RequestReaderHandleResult
+    where
+        R: AsyncBufRead + Unpin,
+    {
+        let mut request: Request = Self::http_head_from_reader(reader, config).await?;
+        if request.is_expect_continue() && config.expect_continue == ExpectContinue::Manual {
+            return Ok(request);
+        }
+        Self::http_body_from_reader(reader, config, &mut request).await?;
+        Ok(request)
+    }
+
+    /// Reads the body of an HTTP request whose head has already been read.
+    ///
+    /// # Parameters
+    /// - `reader`: A mut reference to a buffered reader positioned at the start of the body.
+    /// - `config`: The limits applied while reading.
+    /// - `request`: The request receiving the body and, for chunked bodies, the trailers.
+    ///
+    /// # Returns
+    /// - `Ok`: If the body was read.
+    /// - `Err`: An `RequestError` if the body is invalid, exceeds a limit or cannot be read.
+    async fn http_body_from_reader<R>(
+        reader: &mut R,
+        config: &RequestConfig,
+        request: &mut Self,
+    ) -> Result<(), RequestError>
+    where
+        R: AsyncBufRead + Unpin,
+    {
+        request.body = if request.is_chunked() {
+            Self::chunked_body_from_reader(reader, config, &mut request.trailers).await?
+        } else {
+            let content_length: usize =
+                Self::content_length_from_headers(&request.headers)?.unwrap_or(0);
+            if content_length > config.max_body_size {
+                return Err(RequestError::PayloadTooLarge(config.max_body_size));
+            }
+            let mut body: RequestBody = vec![0; content_length];
+            Self::read_body_exact(reader, &mut body, 0).await?;
+            body
+        };
+        Ok(())
+    }
+
+    /// Reads the request line and headers of an HTTP request, leaving the body unread.
+    ///
+    /// The returned request has an empty body. This allows the body to be streamed
+    /// from the same reader afterwards, e.g. with `get_multipart_reader`.
+    ///
+    /// # Parameters
+    /// - `reader`: A mut reference to a buffered reader, such as a `BufReader<&mut TcpStream>`.
+    /// - `config`: The limits applied while reading.
+    ///
+    /// # Returns
+    /// - `Ok`: A `Request` object populated with the request line and headers.
+    /// - `Err`: An `RequestError` if the head is invalid, exceeds a limit or cannot be read.
+    pub async fn http_head_from_reader<R>(
+        reader: &mut R,
+        config: &RequestConfig,
+    ) -> RequestReaderHandleResult
+    where
+        R: AsyncBufRead + Unpin,
+    {
+        let request_line: String = Self::read_line_with_limit(
+            reader,
+            config.max_request_line_length,
+            RequestError::RequestLineTooLong(config.max_request_line_length),
+        )
+        .await?;
+        let parts: Vec<&str> = request_line.split_whitespace().collect();
+        let parts_len: usize = parts.len();
+        if parts_len < 3 {
+            return Err(RequestError::InvalidHttpRequestPartsLength(parts_len));
+        }
+        let headers: RequestHeaders = Self::headers_from_reader(reader, config).await?;
+        Self::from_parts(parts[0], parts[1], parts[2], headers)
+    }
+
+    /// Parses a request head borrowed from a byte buffer into a `Request`.
+    ///
+    /// Header keys are lowercased and values that are not valid UTF-8 are converted
+    /// lossily. The returned request has an empty body.
+    ///
+    /// # Parameters
+    /// - `head`: The head returned by `RequestHead::parse`.
+    ///
+    /// # Returns
+    /// - `Ok`: A `Request` object populated with the request line and headers.
+    /// - `Err`: An `RequestError` if the target, the `Host` header or the body framing is invalid.
+    pub fn from_head(head: &RequestHead<'_>) -> RequestReaderHandleResult {
+        let mut headers: RequestHeaders = HeaderMap::with_capacity(head.headers.len());
+        for (key, value) in head.get_headers() {
+            headers.append(*key, String::from_utf8_lossy(value));
+        }
+        Self::from_parts(head.method, head.target, head.version, headers)
+    }
+
+    /// Builds a `Request` from the parts of its request line and its headers.
+    ///
+    /// # Parameters
+    /// - `method`: The method from the request line.
+    /// - `target`: The request target from the request line.
+    /// - `version`: The version from the request line.
+    /// - `headers`: The request headers.
+    ///
+    /// # Returns
+    /// - `Ok`: A `Request` object with an empty body.
+    /// - `Err`: An `RequestError` if the target, the `Host` header or the body framing is invalid.
+    fn from_parts(
+        method: &str,
+        target: &str,
+        version: &str,
+        headers: RequestHeaders,
+    ) -> RequestReaderHandleResult {
+        let method: RequestMethod = method.parse::<RequestMethod>().unwrap_or_default();
+        let target: RequestTarget = target.to_string();
+        let version: RequestVersion = version.parse::<RequestVersion>().unwrap_or_default();
+        let target_form: RequestTargetForm = RequestTargetForm::classify(&method, &target);
+        let (target_host, full_path): (Option<(RequestHost, Authority)>, RequestPath) =
+            match target_form {
+                RequestTargetForm::Absolute => {
+                    let url: HttpUrlComponents = HttpUrlComponents::parse(&target)
+                        .map_err(|_| RequestError::InvalidUrl(target.clone()))?;
+                    let host: RequestHost = match (url.host, url.port) {
+                        (Some(host), Some(port)) => {
+                            format!("{}{}{}", host, COLON_SPACE_SYMBOL, port)
+                        }
+                        (Some(host), None) => host,
+                        (None, _) => return Err(RequestError::InvalidUrl(target)),
+                    };
+                    let authority: Authority = host
+                        .parse()
+                        .map_err(|_| RequestError::InvalidUrl(target.clone()))?;
+                    let mut full_path: RequestPath = url.path.unwrap_or_default();
+                    if let Some(query) = url.query {
+                        full_path.push_str(QUERY_SYMBOL);
+                        full_path.push_str(&query);
+                    }
+                    (Some((host, authority)), full_path)
+                }
+                RequestTargetForm::Authority => {
+                    let authority: Authority = target
+                        .parse::<Authority>()
+                        .ok()
+                        .filter(|authority| authority.get_port().is_some())
+                        .ok_or_else(|| RequestError::InvalidUrl(target.clone()))?;
+                    (Some((target.clone(), authority)), String::new())
+                }
+                RequestTargetForm::Origin | RequestTargetForm::Asterisk => (None, target.clone()),
+            };
+        let hash_index: OptionUsize = full_path.find(HASH_SYMBOL);
+        let query_index: OptionUsize = full_path.find(QUERY_SYMBOL);
+        let query_string: RequestQueryString = query_index.map_or(String::new(), |i| {
+            let temp: &str = &full_path[i + 1..];
+            if hash_index.is_none() || hash_index.unwrap() <= i {
+                return temp.to_string();
+            }
+            temp.split(HASH_SYMBOL)
+                .next()
+                .unwrap_or_default()
+                .to_string()
+        });
+        let querys: RequestQuerys = Self::parse_querys(&query_string);
+        let path: RequestPath = if let Some(i) = query_index.or(hash_index) {
+            full_path[..i].to_string()
+        } else {
+            full_path
+        };
+        let normalized_path: RequestPath = Self::normalize_path(&path)?;
+        let header_host: Option<(RequestHost, OptionAuthority)> =
+            Self::parse_host_header(&headers, &version)?;
+        Self::check_framing(&headers)?;
+        let (host, authority): (RequestHost, OptionAuthority) = match (target_host, header_host) {
+            (Some((host, authority)), _) => (host, Some(authority)),
+            (None, Some((host, authority))) => (host, authority),
+            (None, None) => (String::new(), None),
+        };
+        let cookies: RequestCookies = Cookie::parse_all(headers.get_all(COOKIE));
+        Ok(Request {
+            method,
+            host,
+            authority,
+            version,
+            target,
+            target_form,
+            path,
+            normalized_path,
+            query_string,
+            querys,
+            headers,
+            trailers: HeaderMap::new(),
+            cookies,
+            body: Vec::new(),
+        })
+    }
+
+    /// Validates the `Host` header and parses it into an authority.
+    ///
+    /// More than one `Host` header or an invalid value is rejected for every
+    /// version, and a missing `Host` header is rejected for HTTP/1.1. An empty
+    /// value is accepted without an authority.
+    ///
+    /// # Parameters
+    /// - `headers`: The request headers.
+    /// - `version`: The HTTP version of the request.
+    ///
+    /// # Returns
+    /// - `Ok(Some)`: The raw header value and its authority.
+    /// - `Ok(None)`: If no `Host` header was sent by an HTTP/1.0 client.
+    /// - `Err`: `MissingHost`, `DuplicateHost` or `InvalidHost`.
+    fn parse_host_header(
+        headers: &RequestHeaders,
+        version: &RequestVersion,
+    ) -> Result<Option<(RequestHost, OptionAuthority)>, RequestError> {
+        let hosts: Vec<&RequestHeadersValue> = headers.get_all(HOST);
+        if hosts.len() > 1 {
+            return Err(RequestError::DuplicateHost);
+        }
+        let host: &RequestHeadersValue = match hosts.first() {
+            Some(host) => host,
+            None if version.is_http1_1() => return Err(RequestError::MissingHost),
+            None => return Ok(None),
+        };
+        if host.is_empty() {
+            return Ok(Some((String::new(), None)));
+        }
+        let authority: Authority = host
+            .parse()
+            .map_err(|_| RequestError::InvalidHost(host.to_string()))?;
+        Ok(Some((host.to_string(), Some(authority))))
+    }
+
+    /// Reads header lines up to the first empty line.
+    ///
+    /// Keys are lowercased. Lines without a colon count towards the limits but are skipped.
+    ///
+    /// # Parameters
+    /// - `reader`: A mut reference to a buffered reader, such as a `BufReader<&mut TcpStream>`.
+    /// - `config`: The header limits applied while reading.
+    ///
+    /// # Returns
+    /// - `Ok`: The parsed headers.
+    /// - `Err`: A `RequestError` if a header limit is exceeded or the headers cannot be read.
+    pub(crate) async fn headers_from_reader<R>(
+        reader: &mut R,
+        config: &RequestConfig,
+    ) -> Result<RequestHeaders, RequestError>
+    where
+        R: AsyncBufRead + Unpin,
+    {
+        let mut headers: RequestHeaders = HeaderMap::new();
+        let mut header_bytes: usize = 0;
+        let mut header_count: usize = 0;
+        loop {
+            let header_line: String = Self::read_line_with_limit(
+                reader,
+                config.max_header_bytes - header_bytes,
+                RequestError::HeadersTooLarge(config.max_header_bytes),
+            )
+            .await?;
+            header_bytes += header_line.len();
+            let header_line: &str = header_line.trim();
+            if header_line.is_empty() {
+                break;
+            }
+            header_count += 1;
+            if header_count > config.max_header_count {
+                return Err(RequestError::TooManyHeaders(config.max_header_count));
+            }
+            if let Some((key, value)) = Self::parse_header_line(header_line) {
+                headers.append(key, value);
+            }
+        }
+        Ok(headers)
+    }
+
+    /// Reads a single line, failing once it grows beyond `max_length` bytes.
+    ///
+    /// The line terminator counts towards the limit and is kept in the returned line.
+    /// Invalid UTF-8 is replaced with the Unicode replacement character.
+    ///
+    /// # Parameters
+    /// - `reader`: A mut reference to a buffered reader, such as a `BufReader<&mut TcpStream>`.
+    /// - `max_length`: The maximum line length in bytes.
+    /// - `exceeded`: The error returned when the line is longer than `max_length`.
+    ///
+    /// # Returns
+    /// - `Ok`: The line read, including its terminator.
+    /// - `Err`: `exceeded` if the line is too long, `RequestError::ClientDisconnected` if the
+    ///   stream ends before the line terminator, or the mapped I/O error if reading fails.
+    pub(crate) async fn read_line_with_limit<R>(
+        reader: &mut R,
+        max_length: usize,
+        exceeded: RequestError,
+    ) -> Result<String, RequestError>
+    where
+        R: AsyncBufRead + Unpin,
+    {
+        let mut line: Vec<u8> = Vec::new();
+        loop {
+            let available: &[u8] = reader
+                .fill_buf()
+                .await
+                .map_err(RequestError::from_io_error)?;
+            if available.is_empty() {
+                return Err(RequestError::ClientDisconnected);
+            }
+            let (used, is_line_end) = match available.iter().position(|&byte| byte == BR_BYTES[0]) {
+                Some(index) => (index + 1, true),
+                None => (available.len(), false),
+            };
+            if line.len() + used > max_length {
+                return Err(exceeded);
+            }
+            line.extend_from_slice(&available[..used]);
+            reader.consume(used);
+            if is_line_end {
+                break;
+            }
+        }
+        Ok(String::from_utf8_lossy(&line).into_owned())
+    }
+
+    /// Fills `body` from the reader, starting at `start`.
+    ///
+    /// # Parameters
+    /// - `reader`: A mut reference to a buffered reader, such as a `BufReader<&mut TcpStream>`.
+    /// - `body`: The buffer to fill up to its length.
+    /// - `start`: The number of bytes of `body` already received.
+    ///
+    /// # Returns
+    /// - `Ok`: If `body` was filled.
+    /// - `Err`: `RequestError::IncompleteBody` if the stream ends first, or the mapped I/O
+    ///   error if reading fails.
+    pub(crate) async fn read_body_exact<R>(
+        reader: &mut R,
+        body: &mut [u8],
+        start: usize,
+    ) -> Result<(), RequestError>
+    where
+        R: AsyncBufRead + Unpin,
+    {
+        let mut received: usize = start;
+        while received < body.len() {
+            let len: usize = AsyncReadExt::read(reader, &mut body[received..])
+                .await
+                .map_err(RequestError::from_io_error)?;
+            if len == 0 {
+                return Err(RequestError::IncompleteBody {
+                    expected: body.len(),
+                    received,
+                });
+            }
+            received += len;
+        }
+        Ok(())
+    }
+
+    /// Reads a body sent with `Transfer-Encoding: chunked` from the reader.
+    ///
+    /// Chunk extensions are accepted and ignored. Trailer headers following the
+    /// last chunk are lowercased, stored in `trailers` and subject to the header limits.
+    ///
+    /// # Parameters
+    /// - `reader`: A mut reference to a buffered reader, such as a `BufReader<&mut TcpStream>`.
+    /// - `config`: The buffer size and limits applied while reading.
+    /// - `trailers`: The map receiving the trailer headers.
+    ///
+    /// # Returns
+    /// - `Ok`: The decoded body.
+    /// - `Err`: A `RequestError` if a chunk is malformed, exceeds a limit or cannot be read.
+    pub(crate) async fn chunked_body_from_reader<R>(
+        reader: &mut R,
+        config: &RequestConfig,
+        trailers: &mut RequestTrailers,
+    ) -> RequestBodyResult
+    where
+        R: AsyncBufRead + Unpin,
+    {
+        let mut body: RequestBody = Vec::new();
+        loop {
+            let size_line: String = Self::read_line_with_limit(
+                reader,
+                config.buffer_size,
+                RequestError::InvalidChunkSize(format!(
+                    "size line longer than {} bytes",
+                    config.buffer_size
+                )),
+            )
+            .await?;
+            let chunk_size: usize = Self::parse_chunk_size(&size_line)?;
+            if chunk_size == 0 {
+                break;
+            }
+            let start: usize = body.len();
+            let end: usize = start
+                .checked_add(chunk_size)
+                .ok_or_else(|| RequestError::InvalidChunkSize(size_line.trim().to_string()))?;
+            if end > config.max_body_size {
+                return Err(RequestError::PayloadTooLarge(config.max_body_size));
+            }
+            body.resize(end, 0);
+            Self::read_body_exact(reader, &mut body, start).await?;
+            let mut chunk_end: [u8; 2] = [0; 2];
+            AsyncReadExt::read_exact(reader, &mut chunk_end)
+                .await
+                .map_err(RequestError::from_io_error)?;
+            if chunk_end != HTTP_BR_BYTES {
+                return Err(RequestError::InvalidChunkTerminator);
+            }
+        }
+        trailers.extend(Self::headers_from_reader(reader, config).await?);
+        Ok(body)
+    }
+
+    /// Parses the size line of a chunk, ignoring any chunk extensions.
+    ///
+    /// # Parameters
+    /// - `size_line`: The raw chunk size line, e.g. `"1a;name=value\r\n"`.
+    ///
+    /// # Returns
+    /// - `Ok`: The chunk size in bytes.
+    /// - `Err`: `RequestError::InvalidChunkSize` if the size is not a valid hexadecimal number.
+    fn parse_chunk_size(size_line: &str) -> Result<usize, RequestError> {
+        let size_str: &str = size_line.split(SEMICOLON).next().unwrap_or_default().trim();
+        if size_str.is_empty() || !size_str.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return Err(RequestError::InvalidChunkSize(size_str.to_string()));
+        }
+        usize::from_str_radix(size_str, 16)
+            .map_err(|_| RequestError::InvalidChunkSize(size_str.to_string()))
+    }
+
+    /// Splits a header line into a lowercased key and a trimmed value.
+    ///
+    /// # Parameters
+    /// - `header_line`: A single header line without the trailing CRLF.
+    ///
+    /// # Returns
+    /// - `OptionRequestHeader`: The key-value pair, or `None` if the line has no colon.
+    fn parse_header_line(header_line: &str) -> OptionRequestHeader {
+        let (key_part, value_part) = header_line.split_once(COLON_SPACE_SYMBOL)?;
+        Some((
+            key_part.trim().to_ascii_lowercase(),
+            value_part.trim().to_string(),
+        ))
+    }
+
+    /// Checks whether a `Transfer-Encoding` value ends with the `chunked` coding.
+    ///
+    /// # Parameters
+    /// - `transfer_encoding`: The `Transfer-Encoding` header value.
+    ///
+    /// # Returns
+    /// - `bool`: `true` if `chunked` is the final transfer coding; otherwise, `false`.
+    pub(crate) fn is_chunked_transfer_encoding(transfer_encoding: &str) -> bool {
+        transfer_encoding
+            .rsplit(COMMA)
+            .next()
+            .is_some_and(|coding| coding.trim().eq_ignore_ascii_case(CHUNKED))
+    }
+
+    /// Joins the `Transfer-Encoding` headers of a message into a single coding list.
+    ///
+    /// # Parameters
+    /// - `headers`: The message headers.
+    ///
+    /// # Returns
+    /// - `OptionString`: The codings of every header in order, or `None` if there is none.
+    pub(crate) fn transfer_encoding_from_headers(headers: &HeaderMap) -> OptionString {
+        let values: Vec<&String> = headers.get_all(TRANSFER_ENCODING);
+        if values.is_empty() {
+            return None;
+        }
+        Some(
+            values
+                .into_iter()
+                .map(String::as_str)
+                .collect::<Vec<&str>>()
+                .join(COMMA),
+        )
+    }
+
+    /// Checks that the body length of a request can be determined unambiguously.
+    ///
+    /// A request with `Transfer-Encoding` must end with the `chunked` coding and must
+    /// not also carry `Content-Length`, since the body end could otherwise be misread.
     ///
     /// # Parameters
-    /// - `reader`: A mut reference to a `&mut BufReader<&mut TcpStream>`.
-    /// - `buffer_size`: Request buffer size.
+    /// - `headers`: The request headers.
     ///
     /// # Returns
-    /// - `Ok`: A `Request` object populated with the HTTP request data.
-    /// - `Err`: An `RequestError` if the request is invalid or cannot be read.
-    pub async fn http_from_reader(
-        reader: &mut BufReader<&mut TcpStream>,
-        buffer_size: usize,
-    ) -> RequestReaderHandleResult {
-        let mut request_line: String = String::with_capacity(buffer_size);
-        let _ = AsyncBufReadExt::read_line(reader, &mut request_line).await;
-        let parts: Vec<&str> = request_line.split_whitespace().collect();
-        let parts_len: usize = parts.len();
-        if parts_len < 3 {
-            return Err(RequestError::InvalidHttpRequestPartsLength(parts_len));
-        }
-        let method: RequestMethod = parts[0].parse::<RequestMethod>().unwrap_or_default();
-        let full_path: RequestPath = parts[1].to_string();
-        let version: RequestVersion = parts[2].parse::<RequestVersion>().unwrap_or_default();
-        let hash_index: OptionUsize = full_path.find(HASH_SYMBOL);
-        let query_index: OptionUsize = full_path.find(QUERY_SYMBOL);
-        let query_string: String = query_index.map_or(String::new(), |i| {
-            let temp: &str = &full_path[i + 1..];
-            if hash_index.is_none() || hash_index.unwrap() <= i {
-                return temp.to_string();
-            }
-            temp.split(HASH_SYMBOL)
-                .next()
-                .unwrap_or_default()
-                .to_string()
-        });
-        let querys: RequestQuerys = Self::parse_querys(&query_string);
-        let path: RequestPath = if let Some(i) = query_index.or(hash_index) {
-            full_path[..i].to_string()
-        } else {
-            full_path
+    /// - `Ok`: If the framing is valid.
+    /// - `Err`: `RequestError::InvalidTransferEncoding` if the framing is ambiguous.
+    pub(crate) fn check_framing(headers: &HeaderMap) -> Result<(), RequestError> {
+        let Some(transfer_encoding) = Self::transfer_encoding_from_headers(headers) else {
+            return Ok(());
         };
-        let mut headers: RequestHeaders = hash_map_xx_hash3_64();
-        let mut host: RequestHost = String::new();
-        let mut content_length: usize = 0;
-        loop {
-            let mut header_line: String = String::with_capacity(buffer_size);
-            let _ = AsyncBufReadExt::read_line(reader, &mut header_line).await;
-            let header_line: &str = header_line.trim();
-            if header_line.is_empty() {
-                break;
-            }
-            if let Some((key_part, value_part)) = header_line.split_once(COLON_SPACE_SYMBOL) {
-                let key: String = key_part.trim().to_ascii_lowercase();
-                let value: String = value_part.trim().to_string();
-                if key == HOST {
-                    host = value.clone();
-                } else if key == CONTENT_LENGTH {
-                    content_length = value.parse().unwrap_or(0);
+        if !Self::is_chunked_transfer_encoding(&transfer_encoding)
+            || headers.contains_key(CONTENT_LENGTH)
+        {
+            return Err(RequestError::InvalidTransferEncoding(transfer_encoding));
+        }
+        Ok(())
+    }
+
+    /// Parses the `Content-Length` headers of a message.
+    ///
+    /// Repeated headers and comma-separated lists are accepted only if every value
+    /// is the same, since differing lengths would let the body end be misread.
+    ///
+    /// # Parameters
+    /// - `headers`: The message headers.
+    ///
+    /// # Returns
+    /// - `Ok(Some)`: The body length.
+    /// - `Ok(None)`: If there is no `Content-Length` header.
+    /// - `Err`: `RequestError::InvalidContentLength` if a value is not a decimal
+    ///   number or the values differ.
+    pub(crate) fn content_length_from_headers(
+        headers: &HeaderMap,
+    ) -> Result<OptionUsize, RequestError> {
+        let mut content_length: OptionUsize = None;
+        for value in headers.get_all(CONTENT_LENGTH) {
+            for length in value.split(COMMA) {
+                let length: &str = length.trim();
+                let parsed: usize = length
+                    .bytes()
+                    .all(|byte| byte.is_ascii_digit())
+                    .then(|| length.parse().ok())
+                    .flatten()
+                    .ok_or_else(|| RequestError::InvalidContentLength(value.to_string()))?;
+                if content_length.is_some_and(|previous| previous != parsed) {
+                    return Err(RequestError::InvalidContentLength(value.to_string()));
                 }
-                headers.insert(key, value);
+                content_length = Some(parsed);
             }
         }
-        let mut body: RequestBody = vec![0; content_length];
-        if content_length > 0 {
-            let _ = AsyncReadExt::read_exact(reader, &mut body).await;
-        }
-        Ok(Request {
-            method,
-            host,
-            version,
-            path,
-            querys,
-            headers,
-            body,
-        })
+        Ok(content_length)
     }
 
-    /// Creates a new `Request` object from a TCP stream.
+    /// Creates a new `Request` object from a stream.
+    ///
+    /// The idle, header and body deadlines of the stream apply to waiting for the
+    /// first byte, reading the head and reading the body respectively.
+    ///
+    /// For a request expecting `100 Continue`, the interim response is sent before
+    /// the body is read with `ExpectContinue::Auto`, while the body is left unread
+    /// with `ExpectContinue::Manual` so the caller can decide with `accept_continue`
+    /// or `reject_continue`. With `ExpectContinue::Auto`, a declared `Content-Length`
+    /// that is invalid or exceeds the maximum body size is rejected before the
+    /// interim response is sent, so the client never uploads the body.
     ///
     /// # Parameters
     /// - `stream`: A reference to a `&ArcRwLockStream` representing the incoming connection.
-    /// - `buffer_size`: Request buffer size.
+    /// - `config`: The buffer size and limits applied while reading.
     ///
     /// # Returns
     /// - `Ok`: A `Request` object populated with the HTTP request data.
-    /// - `Err`: An `RequestError` if the request is invalid or cannot be read.
-    pub async fn http_request_from_stream(
-        stream: &ArcRwLockStream,
-        buffer_size: usize,
-    ) -> RequestReaderHandleResult {
-        let mut buf_stream: RwLockWriteGuard<'_, TcpStream> = stream.write().await;
-        let mut reader: BufReader<&mut TcpStream> = BufReader::new(&mut buf_stream);
-        Self::http_from_reader(&mut reader, buffer_size).await
+    /// - `Err`: An `RequestError` if the request is invalid, exceeds a limit or cannot be read,
+    ///   `RequestError::RequestTimeout` if a deadline elapses, or
+    ///   `RequestError::ExpectationFailed` if an HTTP/1.1 request expects anything
+    ///   other than `100-continue`.
+    pub async fn http_request_from_stream<S>(
+        stream: &ArcRwLockStream<S>,
+        config: &RequestConfig,
+    ) -> RequestReaderHandleResult
+    where
+        S: SplitStream,
+    {
+        let mut request: Request = Self::http_head_from_stream(stream, config).await?;
+        if request.is_expect_continue() {
+            if config.expect_continue == ExpectContinue::Manual {
+                return Ok(request);
+            }
+            request.check_declared_body_size(config)?;
+            stream
+                .send_continue()
+                .await
+                .map_err(|err| RequestError::Request(err.to_string()))?;
+        } else if let Some(expect) = request.get_header(EXPECT)
+            && request.is_http1_1_or_higher()
+        {
+            return Err(RequestError::ExpectationFailed(expect));
+        }
+        Self::http_body_from_stream(stream, config, &mut request).await?;
+        Ok(request)
+    }
+
+    /// Accepts a request expecting `100 Continue` by sending the interim response and reading the body.
+    ///
+    /// # Parameters
+    /// - `stream`: A reference to a `&ArcRwLockStream` positioned at the start of the body.
+    /// - `config`: The limits applied while reading.
+    ///
+    /// # Returns
+    /// - `Ok`: If the body was read.
+    /// - `Err`: `RequestError::InvalidContentLength` or `RequestError::PayloadTooLarge`
+    ///   if the declared `Content-Length` is rejected before the interim response is sent,
+    ///   `RequestError::Request` if the interim response cannot be sent, or an
+    ///   `RequestError` if the body is invalid, exceeds a limit or cannot be read.
+    pub async fn accept_continue<S>(
+        &mut self,
+        stream: &ArcRwLockStream<S>,
+        config: &RequestConfig,
+    ) -> Result<(), RequestError>
+    where
+        S: SplitStream,
+    {
+        self.check_declared_body_size(config)?;
+        stream
+            .send_continue()
+            .await
+            .map_err(|err| RequestError::Request(err.to_string()))?;
+        Self::http_body_from_stream(stream, config, self).await
+    }
+
+    /// Checks the declared `Content-Length` against the maximum body size before the body is read.
+    ///
+    /// Chunked bodies declare no length and are only checked while they are read.
+    ///
+    /// # Parameters
+    /// - `config`: The limits applied while reading.
+    ///
+    /// # Returns
+    /// - `Ok`: If the declared length is valid and within the limit, or there is none.
+    /// - `Err`: `RequestError::InvalidContentLength` if the `Content-Length` is invalid,
+    ///   or `RequestError::PayloadTooLarge` if it exceeds the maximum body size.
+    fn check_declared_body_size(&self, config: &RequestConfig) -> Result<(), RequestError> {
+        if self.is_chunked() {
+            return Ok(());
+        }
+        if Self::content_length_from_headers(&self.headers)?
+            .is_some_and(|content_length| content_length > config.max_body_size)
+        {
+            return Err(RequestError::PayloadTooLarge(config.max_body_size));
+        }
+        Ok(())
+    }
+
+    /// Rejects a request expecting `100 Continue` with a `417 Expectation Failed` response.
+    ///
+    /// The response closes the connection, since the client may still send the body.
+    ///
+    /// # Parameters
+    /// - `stream`: A reference to a `&ArcRwLockStream` representing the incoming connection.
+    ///
+    /// # Returns
+    /// - `Ok`: If the response was sent.
+    /// - `Err`: If an error occurs during sending.
+    pub async fn reject_continue<S>(&self, stream: &ArcRwLockStream<S>) -> ResponseResult
+    where
+        S: SplitStream,
+    {
+        let mut response: Response = Response::new();
+        response
+            .set_status_code(HttpStatus::ExpectationFailed.code())
+            .set_header(CONNECTION, CLOSE);
+        stream.send(&response.build()).await?;
+        stream.try_flush().await
+    }
+
+    /// Reads the request line and headers of an HTTP request from a stream, leaving the body unread.
+    ///
+    /// The idle and header deadlines of the stream apply to waiting for the first
+    /// byte and reading the head respectively.
+    ///
+    /// # Parameters
+    /// - `stream`: A reference to a `&ArcRwLockStream` representing the incoming connection.
+    /// - `config`: The limits applied while reading.
+    ///
+    /// # Returns
+    /// - `Ok`: A `Request` object populated with the request line and headers.
+    /// - `Err`: An `RequestError` if the head is invalid, exceeds a limit or cannot be read,
+    ///   or `RequestError::RequestTimeout` if a deadline elapses.
+    pub async fn http_head_from_stream<S>(
+        stream: &ArcRwLockStream<S>,
+        config: &RequestConfig,
+    ) -> RequestReaderHandleResult
+    where
+        S: SplitStream,
+    {
+        let timeouts: StreamTimeouts = stream.get_timeouts();
+        let mut reader: RwLockWriteGuardReadHalf<'_, S> = stream.read_half().await;
+        timeout_option(*timeouts.get_idle_timeout(), reader.fill_buf())
+            .await
+            .ok_or(RequestError::RequestTimeout)?
+            .map_err(RequestError::from_io_error)?;
+        timeout_option(
+            *timeouts.get_header_read_timeout(),
+            Self::http_head_from_reader(&mut *reader, config),
+        )
+        .await
+        .ok_or(RequestError::RequestTimeout)?
+    }
+
+    /// Reads the body of an HTTP request from a stream whose head has already been read.
+    ///
+    /// The body deadline of the stream applies to reading the body.
+    ///
+    /// # Parameters
+    /// - `stream`: A reference to a `&ArcRwLockStream` positioned at the start of the body.
+    /// - `config`: The limits applied while reading.
+    /// - `request`: The request receiving the body and, for chunked bodies, the trailers.
+    ///
+    /// # Returns
+    /// - `Ok`: If the body was read.
+    /// - `Err`: An `RequestError` if the body is invalid, exceeds a limit or cannot be read,
+    ///   or `RequestError::RequestTimeout` if the deadline elapses.
+    pub async fn http_body_from_stream<S>(
+        stream: &ArcRwLockStream<S>,
+        config: &RequestConfig,
+        request: &mut Self,
+    ) -> Result<(), RequestError>
+    where
+        S: SplitStream,
+    {
+        let timeouts: StreamTimeouts = stream.get_timeouts();
+        let mut reader: RwLockWriteGuardReadHalf<'_, S> = stream.read_half().await;
+        timeout_option(
+            *timeouts.get_body_read_timeout(),
+            Self::http_body_from_reader(&mut *reader, config, request),
+        )
+        .await
+        .ok_or(RequestError::RequestTimeout)?
     }
 
-    /// Creates a new `Request` object from a TCP stream.
+    /// Creates a new `Request` object from a stream.
     ///
     /// # Parameters
     /// - `stream`: A reference to a `&ArcRwLockStream` representing the incoming connection.
-    /// - `buffer_size`: Request buffer size.
+    /// - `config`: The buffer size and limits applied while reading.
     /// - `request`: A reference to a `Request` object. This object is used as a template.
     ///
     /// # Returns
     /// - `Ok`: A `Request` object populated with the HTTP request data.
-    /// - `Err`: An `RequestError` if the request is invalid or cannot be read.
-    pub async fn ws_request_from_stream(
-        stream: &ArcRwLockStream,
-        buffer_size: usize,
+    /// - `Err`: An `RequestError` if the request is invalid, exceeds a limit or cannot be read.
+    pub async fn ws_request_from_stream<S>(
+        stream: &ArcRwLockStream<S>,
+        config: &RequestConfig,
         request: &mut Self,
-    ) -> RequestReaderHandleResult {
-        let mut buf_stream: RwLockWriteGuard<'_, TcpStream> = stream.write().await;
-        let mut reader: BufReader<&mut TcpStream> = BufReader::new(&mut buf_stream);
-        Self::ws_from_reader(&mut reader, buffer_size, request).await
+    ) -> RequestReaderHandleResult
+    where
+        S: SplitStream,
+    {
+        let mut reader: RwLockWriteGuardReadHalf<'_, S> = stream.read_half().await;
+        Self::ws_from_reader(&mut *reader, config, request).await
     }
 
-    /// Reads a WebSocket request from a TCP stream and constructs a `Request` object.
+    /// Reads a WebSocket request from a stream and constructs a `Request` object.
     ///
-    /// This function reads data from the provided `BufReader` wrapped around a `TcpStream`.
-    /// It attempts to read up to 1024 bytes into a buffer and constructs a `Request` object
-    /// based on the received data. The request body is set using the received bytes.
+    /// This function reads data from the provided buffered reader, such as a `BufReader` wrapped around a `TcpStream`.
+    /// Only the bytes of the decoded frames are consumed, so data following the
+    /// message stays buffered in the reader for the next call.
     ///
     /// # Arguments
-    /// - `reader` - A mutable reference to a `BufReader` wrapping a `TcpStream`.
+    /// - `reader` - A mutable reference to a buffered reader, such as a `BufReader` wrapping a `TcpStream`.
     ///   This reader is used to read the incoming WebSocket request data.
-    /// - `buffer_size`: - Request buffer size.
+    /// - `config`: - The buffer size and limits applied while reading. The buffered
+    ///   message data may not exceed the maximum body size.
     /// - `request` - A reference to a `Request` object. This object is used as a template.
     ///
     /// # Returns
@@ -147,20 +1159,23 @@ impl Request {
     ///   - If no data is read (`Ok(0)`), an empty `Request` object is returned.
     ///   - If data is successfully read, the request body is set with the received bytes.
     /// - `Err(RequestError::InvalidWebSocketRequest)` - If an error occurs while reading from the stream.
-    pub async fn ws_from_reader(
-        reader: &mut BufReader<&mut TcpStream>,
-        buffer_size: usize,
+    /// - `Err(RequestError::PayloadTooLarge)` - If the message exceeds the maximum body size.
+    pub async fn ws_from_reader<R>(
+        reader: &mut R,
+        config: &RequestConfig,
         request: &mut Self,
-    ) -> RequestReaderHandleResult {
-        let mut dynamic_buffer: Vec<u8> = Vec::with_capacity(buffer_size);
-        let mut temp_buffer: Vec<u8> = vec![0; buffer_size];
+    ) -> RequestReaderHandleResult
+    where
+        R: AsyncBufRead + Unpin,
+    {
+        let mut dynamic_buffer: Vec<u8> = Vec::with_capacity(config.buffer_size);
         let mut full_frame: Vec<u8> = Vec::new();
         let mut error_handle = || {
             request.body.clear();
         };
         loop {
-            let len: usize = match reader.read(&mut temp_buffer).await {
-                Ok(len) => len,
+            let available: &[u8] = match reader.fill_buf().await {
+                Ok(available) => available,
                 Err(err) => {
                     error_handle();
                     if err.kind() == ErrorKind::ConnectionReset
@@ -171,62 +1186,140 @@ impl Request {
                     return Err(RequestError::InvalidWebSocketRequest(err.to_string()));
                 }
             };
-            if len == 0 {
+            if available.is_empty() {
                 error_handle();
                 return Err(RequestError::IncompleteWebSocketFrame);
             }
-            dynamic_buffer.extend_from_slice(&temp_buffer[..len]);
-            while let Some((frame, consumed)) = WebSocketFrame::decode_ws_frame(&dynamic_buffer) {
-                dynamic_buffer.drain(0..consumed);
-                match frame.get_opcode() {
-                    WebSocketOpcode::Close => {
+            let buffered_len: usize = dynamic_buffer.len();
+            let available_len: usize = available.len();
+            dynamic_buffer.extend_from_slice(available);
+            let Some((frame, consumed)) = WebSocketFrame::decode_ws_frame(&dynamic_buffer) else {
+                reader.consume(available_len);
+                if full_frame.len() + dynamic_buffer.len() > config.max_body_size {
+                    error_handle();
+                    return Err(RequestError::PayloadTooLarge(config.max_body_size));
+                }
+                continue;
+            };
+            reader.consume(consumed - buffered_len);
+            dynamic_buffer.clear();
+            match frame.get_opcode() {
+                WebSocketOpcode::Close => {
+                    error_handle();
+                    return Err(RequestError::ClientClosedConnection);
+                }
+                WebSocketOpcode::Ping | WebSocketOpcode::Pong => {}
+                WebSocketOpcode::Text | WebSocketOpcode::Binary => {
+                    full_frame.extend_from_slice(frame.get_payload_data());
+                    if full_frame.len() > config.max_body_size {
                         error_handle();
-                        return Err(RequestError::ClientClosedConnection);
-                    }
-                    WebSocketOpcode::Ping | WebSocketOpcode::Pong => {
-                        continue;
-                    }
-                    WebSocketOpcode::Text | WebSocketOpcode::Binary => {
-                        full_frame.extend_from_slice(frame.get_payload_data());
-                        if *frame.get_fin() {
-                            let mut request: Request = request.clone();
-                            request.body = full_frame;
-                            return Ok(request);
-                        }
+                        return Err(RequestError::PayloadTooLarge(config.max_body_size));
                     }
-                    _ => {
-                        error_handle();
-                        return Err(RequestError::InvalidWebSocketFrame(
-                            "Unsupported opcode".into(),
-                        ));
+                    if *frame.get_fin() {
+                        let mut request: Request = request.clone();
+                        request.body = full_frame;
+                        return Ok(request);
                     }
                 }
+                _ => {
+                    error_handle();
+                    return Err(RequestError::InvalidWebSocketFrame(
+                        "Unsupported opcode".into(),
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Percent-decodes a path and resolves its dot segments.
+    ///
+    /// Encoded slashes are decoded before segments are resolved, so `%2F..%2F`
+    /// cannot be used to step outside a path prefix. Empty segments are removed
+    /// and a trailing slash is kept. Paths not starting with `/`, such as `*`,
+    /// are returned unchanged.
+    ///
+    /// # Parameters
+    /// - `raw_path`: The path as sent in the request target.
+    ///
+    /// # Returns
+    /// - `Ok`: The normalized path.
+    /// - `Err`: `RequestError::InvalidPath` if the path contains a NUL byte or invalid UTF-8.
+    fn normalize_path(raw_path: &str) -> RequestPathResult {
+        if !raw_path.starts_with('/') {
+            return Ok(raw_path.to_string());
+        }
+        let raw_bytes: &[u8] = raw_path.as_bytes();
+        let mut decoded: Vec<u8> = Vec::with_capacity(raw_bytes.len());
+        let mut index: usize = 0;
+        while index < raw_bytes.len() {
+            let hex_byte: Option<u8> = (raw_bytes[index] == b'%')
+                .then(|| raw_path.get(index + 1..index + 3))
+                .flatten()
+                .filter(|hex| hex.bytes().all(|byte| byte.is_ascii_hexdigit()))
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+            match hex_byte {
+                Some(byte) => {
+                    decoded.push(byte);
+                    index += 3;
+                }
+                None => {
+                    decoded.push(raw_bytes[index]);
+                    index += 1;
+                }
+            }
+        }
+        if decoded.contains(&0) {
+            return Err(RequestError::InvalidPath(raw_path.to_string()));
+        }
+        let decoded: String = String::from_utf8(decoded)
+            .map_err(|_| RequestError::InvalidPath(raw_path.to_string()))?;
+        let mut segments: Vec<&str> = Vec::new();
+        let mut has_trailing_slash: bool = false;
+        for segment in decoded.split('/').skip(1) {
+            has_trailing_slash = matches!(segment, "" | "." | "..");
+            match segment {
+                "" | "." => {}
+                ".." => {
+                    segments.pop();
+                }
+                _ => segments.push(segment),
             }
         }
+        let mut path: RequestPath = String::with_capacity(decoded.len());
+        for segment in &segments {
+            path.push('/');
+            path.push_str(segment);
+        }
+        if has_trailing_slash || path.is_empty() {
+            path.push('/');
+        }
+        Ok(path)
     }
 
-    /// Parse querys
+    /// Parses a query string into percent-decoded parameters.
+    ///
+    /// `+` is decoded as a space, and parameters with an empty key are skipped.
+    /// Repeated keys keep all of their values in order.
     ///
     /// # Parameters
-    /// - `query`: &str
+    /// - `query`: The raw query string without the leading `?`.
     ///
     /// # Returns
-    /// - RequestQuerys
+    /// - `RequestQuerys`: The decoded query parameters.
     fn parse_querys(query: &str) -> RequestQuerys {
         let mut query_map: RequestQuerys = hash_map_xx_hash3_64();
-        for pair in query.split(AND) {
-            if let Some((key, value)) = pair.split_once(EQUAL) {
-                if !key.is_empty() {
-                    query_map.insert(key.to_string(), value.to_string());
-                }
-            } else if !pair.is_empty() {
-                query_map.insert(pair.to_string(), String::new());
+        for (key, value) in url::form_urlencoded::parse(query.as_bytes()) {
+            if !key.is_empty() {
+                query_map
+                    .entry(key.into_owned())
+                    .or_default()
+                    .push(value.into_owned());
             }
         }
         query_map
     }
 
-    /// Retrieves the value of a query parameter by its key.
+    /// Retrieves the first value of a query parameter by its key.
     ///
     /// # Parameters
     /// - `key`: The query parameter's key, which can be of any type that implements `Into<RequestQuerysKey>`.
@@ -238,7 +1331,39 @@ impl Request {
     where
         K: Into<RequestQuerysKey>,
     {
-        self.querys.get(&key.into()).cloned()
+        self.querys
+            .get(&key.into())
+            .and_then(|values| values.first())
+            .cloned()
+    }
+
+    /// Retrieves every value of a query parameter in the order they appear.
+    ///
+    /// # Parameters
+    /// - `key`: The query parameter's key, which can be of any type that implements `Into<RequestQuerysKey>`.
+    ///
+    /// # Returns
+    /// - `RequestQuerysValues`: All values of the parameter, empty if the key does not exist.
+    pub fn get_query_all<K>(&self, key: K) -> RequestQuerysValues
+    where
+        K: Into<RequestQuerysKey>,
+    {
+        self.querys.get(&key.into()).cloned().unwrap_or_default()
+    }
+
+    /// Deserializes the query string into a specified type `T`.
+    ///
+    /// # Type Parameters
+    /// - `T`: The target type to deserialize into. It must implement the `DeserializeOwned` trait.
+    ///
+    /// # Returns
+    /// - `Ok(T)`: The deserialized object of type `T` if the deserialization is successful.
+    /// - `Err(UrlEncodedError)`: An error if the query string does not match `T`.
+    pub fn get_querys_as<T>(&self) -> ResultUrlEncodedError<T>
+    where
+        T: DeserializeOwned,
+    {
+        url_encoded_from_str(self.get_query_string())
     }
 
     /// Retrieves the value of a request header by its key.
@@ -253,7 +1378,147 @@ impl Request {
     where
         K: Into<RequestHeadersKey>,
     {
-        self.headers.get(&key.into()).cloned()
+        self.headers.get(key.into()).cloned()
+    }
+
+    /// Retrieves every value of a request header in the order they were received.
+    ///
+    /// # Parameters
+    /// - `key`: The header's key, which can be of any type that implements `Into<RequestHeadersKey>`.
+    ///
+    /// # Returns
+    /// - `Vec<RequestHeadersValue>`: All values of the header, empty if the key does not exist.
+    pub fn get_header_all<K>(&self, key: K) -> Vec<RequestHeadersValue>
+    where
+        K: Into<RequestHeadersKey>,
+    {
+        self.headers
+            .get_all(key.into())
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Appends a header to the request, keeping existing values of the same key.
+    ///
+    /// # Parameters
+    /// - `key`: The header key, which will be converted into a `RequestHeadersKey`.
+    /// - `value`: The value of the header, which will be converted into a `RequestHeadersValue`.
+    ///
+    /// # Returns
+    /// - Returns a mutable reference to the current instance (`&mut Self`), allowing for method chaining.
+    pub fn append_header<K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<RequestHeadersKey>,
+        V: Into<RequestHeadersValue>,
+    {
+        self.headers.append(key, value);
+        self
+    }
+
+    /// Retrieves the value of a request trailer by its key.
+    ///
+    /// # Parameters
+    /// - `key`: The trailer's key, which can be of any type that implements `Into<RequestHeadersKey>`.
+    ///
+    /// # Returns
+    /// - `OptionRequestHeadersValue`: Returns `Some(value)` if the key exists in the request trailers,
+    ///   or `None` if the key does not exist.
+    pub fn get_trailer<K>(&self, key: K) -> OptionRequestHeadersValue
+    where
+        K: Into<RequestHeadersKey>,
+    {
+        self.trailers.get(key.into()).cloned()
+    }
+
+    /// Retrieves the value of a request cookie by its name.
+    ///
+    /// # Parameters
+    /// - `name`: The cookie name, matched case-sensitively.
+    ///
+    /// # Returns
+    /// - `OptionCookieValue`: Returns `Some(value)` if the cookie was sent, or `None` otherwise.
+    pub fn get_cookie<N>(&self, name: N) -> OptionCookieValue
+    where
+        N: AsRef<str>,
+    {
+        self.cookies.get(name.as_ref()).cloned()
+    }
+
+    /// Retrieves the host of the effective authority without the port.
+    ///
+    /// The authority is taken from an absolute-form or authority-form request
+    /// target if present, and from the `Host` header otherwise.
+    ///
+    /// # Returns
+    /// - `Option<&AuthorityHost>`: The host, or `None` if the request has no authority.
+    pub fn get_host_name(&self) -> Option<&AuthorityHost> {
+        self.authority
+            .as_ref()
+            .map(|authority| authority.get_host())
+    }
+
+    /// Retrieves the port of the effective authority.
+    ///
+    /// # Returns
+    /// - `OptionU16`: The port, or `None` if the request has no authority or it has no port.
+    pub fn get_port(&self) -> OptionU16 {
+        self.authority
+            .as_ref()
+            .and_then(|authority| *authority.get_port())
+    }
+
+    /// Builds the full HTTP request as a byte vector.
+    ///
+    /// The request line uses the target exactly as stored. A `Content-Length`
+    /// header is computed from the body unless the body is sent with chunked
+    /// transfer encoding, in which case the body is encoded as a single chunk
+    /// followed by the last chunk and the trailers.
+    ///
+    /// # Returns
+    /// - `RequestData`: request data
+    pub fn build(&self) -> RequestData {
+        let mut request_string: String = String::new();
+        request_string.push_str(&self.method.to_string());
+        request_string.push_str(SPACE);
+        request_string.push_str(&self.target);
+        request_string.push_str(SPACE);
+        request_string.push_str(&self.version.to_string());
+        request_string.push_str(HTTP_BR);
+        let is_chunked: bool = self.is_chunked();
+        let mut has_content_length: bool = false;
+        for (key, value) in self.headers.iter() {
+            if key == CONTENT_LENGTH {
+                has_content_length = true;
+                continue;
+            }
+            Response::push_header(&mut request_string, key, value);
+        }
+        if !is_chunked && (has_content_length || !self.body.is_empty()) {
+            Response::push_header(
+                &mut request_string,
+                CONTENT_LENGTH,
+                &self.body.len().to_string(),
+            );
+        }
+        request_string.push_str(HTTP_BR);
+        let mut request_bytes: RequestData = request_string.into_bytes();
+        if is_chunked {
+            request_bytes.extend_from_slice(&Response::encode_chunk(&self.body));
+            request_bytes.extend_from_slice(&Response::encode_last_chunk(&self.trailers));
+        } else {
+            request_bytes.extend_from_slice(&self.body);
+        }
+        request_bytes
+    }
+
+    /// Checks whether the request body was sent with chunked transfer encoding.
+    ///
+    /// # Returns
+    /// - `bool`: `true` if the final transfer coding is `chunked`; otherwise, `false`.
+    pub fn is_chunked(&self) -> bool {
+        Self::transfer_encoding_from_headers(&self.headers)
+            .is_some_and(|value| Self::is_chunked_transfer_encoding(&value))
     }
 
     /// Retrieves the body content of the object as a UTF-8 encoded string.
@@ -285,6 +1550,86 @@ impl Request {
         json_from_slice(self.get_body())
     }
 
+    /// Deserializes an `application/x-www-form-urlencoded` body into a specified type `T`.
+    ///
+    /// # Type Parameters
+    /// - `T`: The target type to deserialize into. It must implement the `DeserializeOwned` trait.
+    ///
+    /// # Returns
+    /// - `Ok(T)`: The deserialized object of type `T` if the deserialization is successful.
+    /// - `Err(UrlEncodedError)`: An error if the body does not match `T`.
+    pub fn get_body_form<T>(&self) -> ResultUrlEncodedError<T>
+    where
+        T: DeserializeOwned,
+    {
+        url_encoded_from_bytes(self.get_body())
+    }
+
+    /// Retrieves the boundary of a multipart request body from the `Content-Type` header.
+    ///
+    /// # Returns
+    /// - `OptionString`: The boundary, or `None` if the body is not multipart.
+    pub fn get_multipart_boundary(&self) -> OptionString {
+        self.headers
+            .get(CONTENT_TYPE)
+            .and_then(|content_type| multipart_boundary(content_type))
+    }
+
+    /// Parses the body of a `multipart/form-data` request into its parts.
+    ///
+    /// # Returns
+    /// - `Ok`: The parts in the order they appear.
+    /// - `Err`: A `MultipartError` if the request is not multipart or the body is malformed.
+    pub fn get_body_multipart(&self) -> MultipartResult {
+        let boundary: String = self
+            .get_multipart_boundary()
+            .ok_or(MultipartError::MissingBoundary)?;
+        multipart_from_slice(self.get_body(), &boundary)
+    }
+
+    /// Creates a reader that streams the multipart body of a request read with
+    /// `http_head_from_reader`.
+    ///
+    /// The reader is limited to the `Content-Length` of the request, so that it
+    /// stops at the end of the body.
+    ///
+    /// # Parameters
+    /// - `reader`: The reader the request head was read from.
+    /// - `config`: The buffer size and header limit applied while reading.
+    ///
+    /// # Returns
+    /// - `Ok`: A `MultipartReader` over the request body.
+    /// - `Err`: A `MultipartError` if the request is not multipart, has no `Content-Length`,
+    ///   or `MultipartError::InvalidContentLength` if its `Content-Length` is invalid.
+    pub fn get_multipart_reader<'a, R>(
+        &self,
+        reader: &'a mut R,
+        config: &RequestConfig,
+    ) -> Result<MultipartReader<Take<&'a mut R>>, MultipartError>
+    where
+        R: AsyncRead + Unpin,
+    {
+        let boundary: String = self
+            .get_multipart_boundary()
+            .ok_or(MultipartError::MissingBoundary)?;
+        if self.is_chunked() {
+            return Err(MultipartError::MissingContentLength);
+        }
+        let content_length: usize = Self::content_length_from_headers(&self.headers)
+            .map_err(|err| match err {
+                RequestError::InvalidContentLength(length) => {
+                    MultipartError::InvalidContentLength(length)
+                }
+                err => MultipartError::Read(err.to_string()),
+            })?
+            .ok_or(MultipartError::MissingContentLength)?;
+        Ok(MultipartReader::new(
+            AsyncReadExt::take(reader, content_length as u64),
+            &boundary,
+            config,
+        ))
+    }
+
     /// Converts the request to a formatted string representation.
     ///
     /// - Returns: A `String` containing formatted request details.
@@ -296,13 +1641,18 @@ impl Request {
             BINARY
         };
         format!(
-            "[Request] => [method]: {}; [host]: {}; [version]: {}; [path]: {}; [querys]: {:?}; [headers]: {:?}; [body]: {} bytes {};",
+            "[Request] => [method]: {}; [host]: {}; [authority]: {:?}; [version]: {}; [target]: {}; [path]: {}; [normalized_path]: {}; [querys]: {:?}; [headers]: {:?}; [trailers]: {:?}; [cookies]: {:?}; [body]: {} bytes {};",
             self.get_method(),
             self.get_host(),
+            self.get_authority(),
             self.get_version(),
+            self.get_target(),
             self.get_path(),
+            self.get_normalized_path(),
             self.get_querys(),
             self.get_headers(),
+            self.get_trailers(),
+            self.get_cookies(),
             body.len(),
             body_type
         )
@@ -502,4 +1852,17 @@ impl Request {
         }
         self.is_http1_1_or_higher() || self.is_ws()
     }
+
+    /// Checks whether the client waits for a `100 Continue` response before sending the body.
+    ///
+    /// The expectation is only honored for HTTP/1.1 and higher.
+    ///
+    /// # Returns
+    /// - `bool`: `true` if the request has an `Expect: 100-continue` header; otherwise, `false`.
+    pub fn is_expect_continue(&self) -> bool {
+        self.is_http1_1_or_higher()
+            && self
+                .get_header(EXPECT)
+                .is_some_and(|expect| expect.eq_ignore_ascii_case(EXPECT_100_CONTINUE))
+    }
 }
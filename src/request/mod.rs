@@ -1,8 +1,13 @@
+pub(crate) mod cfg;
+pub(crate) mod r#const;
+pub(crate) mod r#enum;
 pub(crate) mod error;
 pub(crate) mod r#impl;
 pub(crate) mod r#struct;
 pub(crate) mod r#type;
 
+pub use r#const::*;
+pub use r#enum::*;
 pub use error::*;
 pub use r#struct::*;
 pub use r#type::*;
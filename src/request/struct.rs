@@ -7,14 +7,78 @@ pub struct Request {
     pub(super) method: RequestMethod,
     /// The host of the request.
     pub(super) host: RequestHost,
+    /// The effective authority parsed from the request target or the `Host` header.
+    pub(super) authority: OptionAuthority,
     /// The HTTP version used in the request.
     pub(super) version: RequestVersion,
-    /// The request path.
+    /// The request target exactly as sent in the request line.
+    pub(super) target: RequestTarget,
+    /// The form of the request target.
+    pub(super) target_form: RequestTargetForm,
+    /// The request path exactly as sent in the request target.
     pub(super) path: RequestPath,
-    /// The query string of the request.
+    /// The percent-decoded request path with dot segments and empty segments removed.
+    pub(super) normalized_path: RequestPath,
+    /// The raw query string of the request, without percent-decoding.
+    pub(super) query_string: RequestQueryString,
+    /// The percent-decoded query parameters of the request.
     pub(super) querys: RequestQuerys,
     /// A collection of HTTP headers as key-value pairs.
     pub(super) headers: RequestHeaders,
+    /// Trailer headers sent after a chunked request body.
+    pub(super) trailers: RequestTrailers,
+    /// The cookies sent in the `Cookie` headers, keyed by name.
+    pub(super) cookies: RequestCookies,
+    /// The binary body of the request.
+    pub(super) body: RequestBody,
+}
+
+/// Buffer size and limits applied while reading an HTTP request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Data, DisplayDebug)]
+pub struct RequestConfig {
+    /// The buffer size used while reading request data.
+    pub(super) buffer_size: usize,
+    /// The maximum length of the request line in bytes, including the line terminator.
+    pub(super) max_request_line_length: usize,
+    /// The maximum total length of all header lines in bytes.
+    pub(super) max_header_bytes: usize,
+    /// The maximum number of header lines.
+    pub(super) max_header_count: usize,
+    /// The maximum size of the decoded request body in bytes.
+    pub(super) max_body_size: usize,
+    /// How a request expecting `100 Continue` is handled.
+    pub(super) expect_continue: ExpectContinue,
+}
+
+/// The request line and headers of an HTTP request, borrowed from a byte buffer.
+///
+/// Produced by `RequestHead::parse` without copying, and turned into a `Request`
+/// with `Request::from_head`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestHead<'a> {
+    /// The method exactly as sent in the request line.
+    pub(super) method: &'a str,
+    /// The request target exactly as sent in the request line.
+    pub(super) target: &'a str,
+    /// The version exactly as sent in the request line.
+    pub(super) version: &'a str,
+    /// The header names and values in the order they were sent, with surrounding whitespace removed.
+    pub(super) headers: RequestHeadHeaders<'a>,
+}
+
+/// Builds a `Request` from its method, path, query parameters, headers and body.
+#[derive(Debug, Clone, PartialEq, Eq, Getter, DisplayDebug)]
+pub struct RequestBuilder {
+    /// The HTTP method of the request.
+    pub(super) method: RequestMethod,
+    /// The HTTP version of the request.
+    pub(super) version: RequestVersion,
+    /// The request path, or the whole target for absolute-form, authority-form and asterisk-form.
+    pub(super) path: RequestPath,
+    /// The query parameters in the order they are encoded into the target.
+    pub(super) querys: RequestBuilderQuerys,
+    /// The request headers.
+    pub(super) headers: RequestHeaders,
     /// The binary body of the request.
     pub(super) body: RequestBody,
 }
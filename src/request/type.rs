@@ -6,14 +6,22 @@ pub type RequestMethod = Method;
 pub type RequestHost = String;
 /// The HTTP version (e.g., HTTP/1.1).
 pub type RequestVersion = HttpVersion;
+/// The request target exactly as sent in the request line.
+pub type RequestTarget = String;
 /// The path portion of the request URL.
 pub type RequestPath = String;
+/// The result type returned when normalizing a request path.
+pub type RequestPathResult = Result<RequestPath, RequestError>;
 /// Key type used in the request query parameters.
 pub type RequestQuerysKey = String;
 /// Value type used in the request query parameters.
 pub type RequestQuerysValue = String;
-/// All query parameters parsed from the request URL.
-pub type RequestQuerys = HashMapXxHash3_64<RequestQuerysKey, RequestQuerysValue>;
+/// All values of a query parameter in the order they appear.
+pub type RequestQuerysValues = Vec<RequestQuerysValue>;
+/// All query parameters parsed from the request URL, keeping repeated keys.
+pub type RequestQuerys = HashMapXxHash3_64<RequestQuerysKey, RequestQuerysValues>;
+/// The raw query string of the request URL, without the leading `?`.
+pub type RequestQueryString = String;
 /// The raw binary body of the request.
 pub type RequestBody = Vec<u8>;
 /// The request body as a UTF-8 string.
@@ -22,8 +30,12 @@ pub type RequestBodyString = String;
 pub type RequestHeadersKey = String;
 /// Value type used in the request headers.
 pub type RequestHeadersValue = String;
-/// All headers sent with the HTTP request.
-pub type RequestHeaders = HashMapXxHash3_64<RequestHeadersKey, RequestHeadersValue>;
+/// All headers sent with the HTTP request, including repeated ones.
+pub type RequestHeaders = HeaderMap;
+/// Trailer headers sent after the last chunk of a chunked request body.
+pub type RequestTrailers = HeaderMap;
+/// Cookies sent with the HTTP request, keyed by name.
+pub type RequestCookies = Cookies;
 /// The result type returned from a request reader handler.
 pub type RequestReaderHandleResult = Result<Request, RequestError>;
 /// Read guard for a `Request` wrapped in a `RwLock`.
@@ -34,3 +46,18 @@ pub type RwLockWriteGuardRequest<'a> = RwLockWriteGuard<'a, Request>;
 pub type OptionRequestQuerysValue = Option<RequestQuerysValue>;
 /// Optional value for a header.
 pub type OptionRequestHeadersValue = Option<RequestHeadersValue>;
+/// Optional key-value pair parsed from a single header line.
+pub type OptionRequestHeader = Option<(RequestHeadersKey, RequestHeadersValue)>;
+/// The result type returned when decoding a request body.
+pub type RequestBodyResult = Result<RequestBody, RequestError>;
+/// A header name and its raw value borrowed from a request head.
+pub type RequestHeadHeader<'a> = (&'a str, &'a [u8]);
+/// The headers borrowed from a request head, in the order they were sent.
+pub type RequestHeadHeaders<'a> = Vec<RequestHeadHeader<'a>>;
+/// The result of parsing a request head: the head and the number of bytes it
+/// occupies, or `None` if the buffer does not hold a complete head yet.
+pub type RequestHeadResult<'a> = Result<Option<(RequestHead<'a>, usize)>, RequestError>;
+/// The query parameters of a `RequestBuilder` in the order they are encoded.
+pub type RequestBuilderQuerys = Vec<(RequestQuerysKey, RequestQuerysValue)>;
+/// The full serialized binary content of an HTTP request.
+pub type RequestData = Vec<u8>;
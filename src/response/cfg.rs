@@ -0,0 +1,140 @@
+#[test]
+fn test_build_chunked() {
+    use crate::*;
+    let mut response: Response = Response::new();
+    response
+        .set_header(TRANSFER_ENCODING, CHUNKED)
+        .set_header(CONTENT_TYPE, TEXT_PLAIN)
+        .set_body("hello");
+    let response_string: String = String::from_utf8(response.build()).unwrap();
+    assert!(!response_string.contains(CONTENT_LENGTH));
+    assert!(response_string.ends_with("\r\n\r\n5\r\nhello\r\n"));
+}
+
+#[test]
+fn test_encode_last_chunk() {
+    use crate::*;
+    let mut trailers: ResponseTrailers = HeaderMap::new();
+    trailers.insert(ETAG, "\"abc\"");
+    assert_eq!(Response::encode_chunk(b""), b"");
+    assert_eq!(
+        Response::encode_last_chunk(&trailers),
+        b"0\r\netag: \"abc\"\r\n\r\n"
+    );
+}
+
+#[test]
+fn test_build_repeated_headers() {
+    use crate::*;
+    let mut response: Response = Response::new();
+    response
+        .append_header(SET_COOKIE, "a=1")
+        .append_header(SET_COOKIE, "b=2");
+    let response_string: String = String::from_utf8(response.build()).unwrap();
+    assert!(response_string.contains("set-cookie: a=1\r\nset-cookie: b=2\r\n"));
+}
+
+#[tokio::test]
+async fn test_http_from_reader() {
+    use crate::*;
+    let config: RequestConfig = RequestConfig::default();
+    let mut data: &[u8] = b"HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\nhello\
+        HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n2\r\nhe\r\n3\r\nllo\r\n0\r\nEtag: \"a\"\r\n\r\n\
+        HTTP/1.1 100 Continue\r\n\r\n\
+        HTTP/1.1 204 No Content\r\n\r\n\
+        HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\n\
+        HTTP/1.0 404 Not Found\r\n\r\nuntil close";
+    let mut reader: BufReader<&mut &[u8]> = BufReader::new(&mut data);
+    let get: RequestMethod = Method::GET;
+    let response: Response = Response::http_from_reader(&mut reader, &config, &get)
+        .await
+        .unwrap();
+    assert_eq!(response.get_status_code(), &200);
+    assert_eq!(response.get_reason_phrase(), "OK");
+    assert_eq!(response.get_body(), b"hello");
+    let response: Response = Response::http_from_reader(&mut reader, &config, &get)
+        .await
+        .unwrap();
+    assert_eq!(response.get_body(), b"hello");
+    assert_eq!(
+        response.get_trailers().get(ETAG),
+        Some(&"\"a\"".to_string())
+    );
+    for status_code in [100, 204] {
+        let response: Response = Response::http_from_reader(&mut reader, &config, &get)
+            .await
+            .unwrap();
+        assert_eq!(response.get_status_code(), &status_code);
+        assert!(response.get_body().is_empty());
+    }
+    let response: Response = Response::http_from_reader(&mut reader, &config, &Method::HEAD)
+        .await
+        .unwrap();
+    assert!(response.get_body().is_empty());
+    let response: Response = Response::http_from_reader(&mut reader, &config, &get)
+        .await
+        .unwrap();
+    assert_eq!(response.get_version(), &HttpVersion::HTTP1_0);
+    assert_eq!(response.get_reason_phrase(), "Not Found");
+    assert_eq!(response.get_body(), b"until close");
+    let mut data: &[u8] = b"HTTP/1.1 2000 OK\r\n\r\n";
+    let mut reader: BufReader<&mut &[u8]> = BufReader::new(&mut data);
+    assert_eq!(
+        Response::http_from_reader(&mut reader, &config, &get)
+            .await
+            .unwrap_err(),
+        RequestError::InvalidStatusLine("HTTP/1.1 2000 OK".to_string())
+    );
+}
+
+#[test]
+fn test_build_chunked_content_encoding() {
+    use crate::*;
+    let mut response: Response = Response::new();
+    response
+        .set_header(TRANSFER_ENCODING, CHUNKED)
+        .set_header(CONTENT_ENCODING, "gzip")
+        .set_body("hello");
+    let response_string: String = String::from_utf8(response.build()).unwrap();
+    assert!(!response_string.contains(CONTENT_ENCODING));
+    assert!(response_string.ends_with("\r\n\r\n5\r\nhello\r\n"));
+    let mut response: Response = Response::new();
+    response
+        .set_header(CONTENT_ENCODING, "gzip")
+        .set_body("hello");
+    let data: ResponseData = response.build();
+    let head_end: usize = data
+        .windows(4)
+        .position(|window| window == b"\r\n\r\n")
+        .unwrap()
+        + 4;
+    let head: String = String::from_utf8_lossy(&data[..head_end]).into_owned();
+    assert!(head.contains("content-encoding: gzip\r\n"));
+    assert_eq!(
+        Compress::Gzip
+            .decode(&data[head_end..], DEFAULT_BUFFER_SIZE)
+            .as_ref(),
+        b"hello"
+    );
+}
+
+#[test]
+fn test_build_no_content_length() {
+    use crate::*;
+    let mut response: Response = Response::new();
+    response
+        .set_status_code(HttpStatus::NoContent.code())
+        .set_header(CONTENT_LENGTH, "5");
+    let response_string: String = String::from_utf8(response.build()).unwrap();
+    assert!(!response_string.contains(CONTENT_LENGTH));
+    let mut response: Response = Response::new();
+    response.set_status_code(HttpStatus::NotModified.code());
+    let response_string: String = String::from_utf8(response.build()).unwrap();
+    assert!(!response_string.contains(CONTENT_LENGTH));
+    let mut response: Response = Response::new();
+    response
+        .set_status_code(HttpStatus::NotModified.code())
+        .set_header(CONTENT_LENGTH, "5");
+    let response_string: String = String::from_utf8(response.build()).unwrap();
+    assert!(response_string.contains("content-length: 5\r\n"));
+}
@@ -0,0 +1,2 @@
+/// The prefix of the version in an HTTP/1.x status line.
+pub(crate) const HTTP_VERSION_PREFIX: &str = "HTTP/";
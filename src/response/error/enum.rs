@@ -4,8 +4,10 @@ use crate::*;
 pub enum ResponseError {
     NotFoundStream,
     ConnectionClosed,
+    WriteTimeout,
     Unknown(String),
     Response(String),
     WebSocketHandShake(String),
     MethodNotSupported(String),
+    NotInterimStatus(usize),
 }
@@ -11,6 +11,7 @@ impl Display for ResponseError {
             Self::ConnectionClosed => {
                 write!(f, "Connection has been closed")
             }
+            Self::WriteTimeout => write!(f, "Write timed out"),
             Self::Unknown(err) => write!(f, "Unknown error{}{}", COLON_SPACE, err),
             Self::Response(data) => write!(f, "Response error{}{}", COLON_SPACE, data),
             Self::WebSocketHandShake(err) => {
@@ -19,6 +20,13 @@ impl Display for ResponseError {
             Self::MethodNotSupported(err) => {
                 write!(f, "Method not supported{}{}", COLON_SPACE, err)
             }
+            Self::NotInterimStatus(status_code) => {
+                write!(
+                    f,
+                    "Not an interim status code{}{}",
+                    COLON_SPACE, status_code
+                )
+            }
         }
     }
 }
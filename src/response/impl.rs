@@ -16,11 +16,194 @@ impl Response {
             version: HttpVersion::HTTP1_1,
             status_code: 200,
             reason_phrase: EMPTY_STR.to_owned(),
-            headers: hash_map_xx_hash3_64(),
+            headers: HeaderMap::new(),
+            trailers: HeaderMap::new(),
             body: Vec::new(),
         }
     }
 
+    /// Reads an HTTP response from a buffered reader.
+    ///
+    /// Responses to `HEAD` requests, successful responses to `CONNECT` requests and
+    /// responses with a 1xx, 204 or 304 status have no body. Otherwise the body is
+    /// read as chunked, by `Content-Length`, or until the connection is closed.
+    /// Interim 1xx responses are returned as they are, so the caller reads again
+    /// for the final response.
+    ///
+    /// # Parameters
+    /// - `reader`: A mut reference to a buffered reader, such as a `BufReader<&mut TcpStream>`.
+    /// - `config`: The buffer size and limits applied while reading.
+    /// - `method`: The method of the request this response answers.
+    ///
+    /// # Returns
+    /// - `Ok`: A `Response` object populated with the HTTP response data.
+    /// - `Err`: An `RequestError` if the response is invalid, exceeds a limit or cannot be read.
+    pub async fn http_from_reader<R>(
+        reader: &mut R,
+        config: &RequestConfig,
+        method: &RequestMethod,
+    ) -> ResponseReaderHandleResult
+    where
+        R: AsyncBufRead + Unpin,
+    {
+        let mut response: Response = Self::http_head_from_reader(reader, config).await?;
+        response
+            .http_body_from_reader(reader, config, method)
+            .await?;
+        Ok(response)
+    }
+
+    /// Reads an HTTP response from a stream.
+    ///
+    /// The header and body deadlines of the stream apply to reading the head and
+    /// the body respectively.
+    ///
+    /// # Parameters
+    /// - `stream`: A reference to a `&ArcRwLockStream` representing the connection.
+    /// - `config`: The buffer size and limits applied while reading.
+    /// - `method`: The method of the request this response answers.
+    ///
+    /// # Returns
+    /// - `Ok`: A `Response` object populated with the HTTP response data.
+    /// - `Err`: An `RequestError` if the response is invalid, exceeds a limit or cannot be read,
+    ///   or `RequestError::RequestTimeout` if a deadline elapses.
+    pub async fn http_from_stream<S>(
+        stream: &ArcRwLockStream<S>,
+        config: &RequestConfig,
+        method: &RequestMethod,
+    ) -> ResponseReaderHandleResult
+    where
+        S: SplitStream,
+    {
+        let timeouts: StreamTimeouts = stream.get_timeouts();
+        let mut reader: RwLockWriteGuardReadHalf<'_, S> = stream.read_half().await;
+        let mut response: Response = timeout_option(
+            *timeouts.get_header_read_timeout(),
+            Self::http_head_from_reader(&mut *reader, config),
+        )
+        .await
+        .ok_or(RequestError::RequestTimeout)??;
+        timeout_option(
+            *timeouts.get_body_read_timeout(),
+            response.http_body_from_reader(&mut *reader, config, method),
+        )
+        .await
+        .ok_or(RequestError::RequestTimeout)??;
+        Ok(response)
+    }
+
+    /// Reads the status line and headers of an HTTP response, leaving the body unread.
+    ///
+    /// # Parameters
+    /// - `reader`: A mut reference to a buffered reader, such as a `BufReader<&mut TcpStream>`.
+    /// - `config`: The limits applied while reading.
+    ///
+    /// # Returns
+    /// - `Ok`: A `Response` object populated with the status line and headers.
+    /// - `Err`: An `RequestError` if the head is invalid, exceeds a limit or cannot be read.
+    pub async fn http_head_from_reader<R>(
+        reader: &mut R,
+        config: &RequestConfig,
+    ) -> ResponseReaderHandleResult
+    where
+        R: AsyncBufRead + Unpin,
+    {
+        let status_line: String = Request::read_line_with_limit(
+            reader,
+            *config.get_max_request_line_length(),
+            RequestError::RequestLineTooLong(*config.get_max_request_line_length()),
+        )
+        .await?;
+        let status_line: &str = status_line.trim_end_matches(['\r', '\n']);
+        let invalid_status_line = || RequestError::InvalidStatusLine(status_line.to_string());
+        let mut parts: std::str::SplitN<'_, char> = status_line.splitn(3, ' ');
+        let version: ResponseVersion = parts
+            .next()
+            .filter(|version| version.starts_with(HTTP_VERSION_PREFIX))
+            .ok_or_else(invalid_status_line)?
+            .parse::<ResponseVersion>()
+            .unwrap_or_default();
+        let status_code: ResponseStatusCode = parts
+            .next()
+            .filter(|code| code.len() == 3 && code.bytes().all(|byte| byte.is_ascii_digit()))
+            .and_then(|code| code.parse().ok())
+            .ok_or_else(invalid_status_line)?;
+        let reason_phrase: ResponseReasonPhrase = parts.next().unwrap_or_default().to_string();
+        let headers: ResponseHeaders = Request::headers_from_reader(reader, config).await?;
+        Ok(Response {
+            version,
+            status_code,
+            reason_phrase,
+            headers,
+            trailers: HeaderMap::new(),
+            body: Vec::new(),
+        })
+    }
+
+    /// Reads the body of an HTTP response whose head has already been read.
+    ///
+    /// # Parameters
+    /// - `reader`: A mut reference to a buffered reader positioned at the start of the body.
+    /// - `config`: The limits applied while reading.
+    /// - `method`: The method of the request this response answers.
+    ///
+    /// # Returns
+    /// - `Ok`: If the body was read.
+    /// - `Err`: An `RequestError` if the body is invalid, exceeds a limit or cannot be read.
+    async fn http_body_from_reader<R>(
+        &mut self,
+        reader: &mut R,
+        config: &RequestConfig,
+        method: &RequestMethod,
+    ) -> Result<(), RequestError>
+    where
+        R: AsyncBufRead + Unpin,
+    {
+        if !self.has_body(method) {
+            return Ok(());
+        }
+        self.body = if self.is_chunked() {
+            Request::chunked_body_from_reader(reader, config, &mut self.trailers).await?
+        } else if let Some(content_length) =
+            Request::content_length_from_headers(self.get_headers())?
+        {
+            if content_length > *config.get_max_body_size() {
+                return Err(RequestError::PayloadTooLarge(*config.get_max_body_size()));
+            }
+            let mut body: ResponseBody = vec![0; content_length];
+            Request::read_body_exact(reader, &mut body, 0).await?;
+            body
+        } else {
+            let mut body: ResponseBody = Vec::new();
+            AsyncReadExt::take(reader, *config.get_max_body_size() as u64 + 1)
+                .read_to_end(&mut body)
+                .await
+                .map_err(RequestError::from_io_error)?;
+            if body.len() > *config.get_max_body_size() {
+                return Err(RequestError::PayloadTooLarge(*config.get_max_body_size()));
+            }
+            body
+        };
+        Ok(())
+    }
+
+    /// Checks whether the response carries a body.
+    ///
+    /// # Parameters
+    /// - `method`: The method of the request this response answers.
+    ///
+    /// # Returns
+    /// - `bool`: `false` for responses to `HEAD`, successful responses to `CONNECT`
+    ///   and 1xx, 204 or 304 responses; otherwise, `true`.
+    fn has_body(&self, method: &RequestMethod) -> bool {
+        let status_code: ResponseStatusCode = self.status_code;
+        !(method.is_head()
+            || (method.is_connect() && (200..300).contains(&status_code))
+            || (100..200).contains(&status_code)
+            || status_code == 204
+            || status_code == 304)
+    }
+
     /// Retrieves the value of a response header by its key.
     ///
     /// # Parameters
@@ -34,10 +217,28 @@ impl Response {
         K: Into<ResponseHeadersKey>,
     {
         self.headers
-            .get(&key.into())
+            .get(key.into())
             .and_then(|data| Some(data.clone()))
     }
 
+    /// Retrieves every value of a response header in the order they were added.
+    ///
+    /// # Parameters
+    /// - `key`: The header's key, which can be of any type that implements `Into<ResponseHeadersKey>`.
+    ///
+    /// # Returns
+    /// - `Vec<ResponseHeadersValue>`: All values of the header, empty if the key does not exist.
+    pub fn get_header_all<K>(&self, key: K) -> Vec<ResponseHeadersValue>
+    where
+        K: Into<ResponseHeadersKey>,
+    {
+        self.headers
+            .get_all(key.into())
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
     /// Retrieves the body content of the object as a UTF-8 encoded string.
     ///
     /// This method uses `String::from_utf8_lossy` to convert the byte slice returned by `self.get_body()` into a string.
@@ -69,7 +270,8 @@ impl Response {
 
     /// Adds a header to the response.
     ///
-    /// This function inserts a key-value pair into the response headers.
+    /// This function inserts a key-value pair into the response headers, replacing
+    /// every existing value of the same key.
     /// The key and value are converted into `ResponseHeadersKey`, allowing for efficient handling of both owned and borrowed string data.
     ///
     /// # Parameters
@@ -87,6 +289,39 @@ impl Response {
         self
     }
 
+    /// Appends a header to the response, keeping existing values of the same key.
+    ///
+    /// Every value is written as its own header line, which is required for
+    /// headers such as `Set-Cookie`.
+    ///
+    /// # Parameters
+    /// - `key`: The header key, which will be converted into a `ResponseHeadersKey`.
+    /// - `value`: The value of the header, which will be converted into a `ResponseHeadersValue`.
+    ///
+    /// # Returns
+    /// - Returns a mutable reference to the current instance (`&mut Self`), allowing for method chaining.
+    pub fn append_header<K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<ResponseHeadersKey>,
+        V: Into<ResponseHeadersValue>,
+    {
+        self.headers.append(key.into(), value.into());
+        self
+    }
+
+    /// Validates a cookie and appends it as a `Set-Cookie` header.
+    ///
+    /// # Parameters
+    /// - `cookie`: The `SetCookie` builder describing the cookie.
+    ///
+    /// # Returns
+    /// - `Ok`: A mutable reference to the current instance, allowing for method chaining.
+    /// - `Err`: A `CookieError` if the cookie is invalid.
+    pub fn append_cookie(&mut self, cookie: &SetCookie) -> Result<&mut Self, CookieError> {
+        let value: String = cookie.build()?;
+        Ok(self.append_header(SET_COOKIE, value))
+    }
+
     /// Set the body of the response.
     ///
     /// This method allows you to set the body of the response by converting the provided
@@ -143,7 +378,7 @@ impl Response {
     /// - `response_string`: A mutable reference to the string where the header will be added.
     /// - `key`: The header key as a string slice (`&str`).
     /// - `value`: The header value as a string slice (`&str`).
-    pub(super) fn push_header(response_string: &mut String, key: &str, value: &str) {
+    pub(crate) fn push_header(response_string: &mut String, key: &str, value: &str) {
         response_string.push_str(key);
         response_string.push_str(COLON_SPACE);
         response_string.push_str(value);
@@ -165,6 +400,24 @@ impl Response {
     }
 
     /// Builds the full HTTP response as a byte vector.
+    ///
+    /// When the `Transfer-Encoding` header ends with `chunked`, no `Content-Length`
+    /// is emitted and a non-empty body is encoded as the first chunk. Further chunks
+    /// and the last chunk are then sent through `ArcRwLockStream::send_chunk` and
+    /// `ArcRwLockStream::send_last_chunk`.
+    ///
+    /// A body is compressed according to a `gzip`, `br` or `deflate` `Content-Encoding`
+    /// header only when it is sent with a `Content-Length`. Chunked and event stream
+    /// bodies are sent as they are, so such a `Content-Encoding` header is left out.
+    ///
+    /// An informational 1xx response consists of its status line and the headers
+    /// set on it only: no `Connection`, `Content-Type` or `Content-Length` header
+    /// is added, and the body is not sent.
+    ///
+    /// A `204 No Content` response never carries `Content-Length`. A `304 Not Modified`
+    /// response carries it only if the caller set it, since it then describes the
+    /// selected representation rather than the empty body.
+    ///
     /// # Returns
     /// - `ResponseData`: response data
     pub fn build(&mut self) -> ResponseData {
@@ -175,18 +428,25 @@ impl Response {
         self.push_http_response_first_line(&mut response_string);
         let mut compress_type_opt: OptionCompress = None;
         let mut connection_opt: OptionString = None;
+        let mut content_encoding_opt: OptionString = None;
         let mut content_type_opt: OptionString = None;
-        let headers: ResponseHeaders = self
-            .get_mut_headers()
-            .drain()
-            .map(|(key, value)| (key.to_lowercase(), value))
-            .collect();
+        let mut content_length_opt: OptionString = None;
+        let headers: ResponseHeaders = std::mem::take(self.get_mut_headers());
         let mut unset_content_length: bool = false;
+        let mut is_chunked: bool = false;
         for (key, value) in headers.iter() {
             if key == CONTENT_LENGTH {
+                content_length_opt.get_or_insert_with(|| value.to_owned());
                 continue;
+            } else if key == TRANSFER_ENCODING {
+                if Request::is_chunked_transfer_encoding(value) {
+                    is_chunked = true;
+                    unset_content_length = true;
+                }
             } else if key == CONTENT_ENCODING {
                 compress_type_opt = Some(value.parse::<Compress>().unwrap_or_default());
+                content_encoding_opt = Some(value.to_owned());
+                continue;
             } else if key == CONNECTION {
                 connection_opt = Some(value.to_owned());
             } else if key == CONTENT_TYPE {
@@ -197,6 +457,20 @@ impl Response {
             }
             Self::push_header(&mut response_string, key, value);
         }
+        if let Some(content_encoding) = &content_encoding_opt {
+            let is_compressed: bool = !unset_content_length;
+            if is_compressed
+                || compress_type_opt
+                    .as_ref()
+                    .is_none_or(|compress| compress.is_unknown())
+            {
+                Self::push_header(&mut response_string, CONTENT_ENCODING, content_encoding);
+            }
+        }
+        if self.is_informational() {
+            response_string.push_str(HTTP_BR);
+            return response_string.into_bytes();
+        }
         if connection_opt.is_none() {
             Self::push_header(&mut response_string, CONNECTION, KEEP_ALIVE);
         }
@@ -209,8 +483,16 @@ impl Response {
             content_type.push_str(CHARSET_UTF_8);
             Self::push_header(&mut response_string, CONTENT_TYPE, &content_type);
         }
+        let status_code: ResponseStatusCode = *self.get_status_code();
         let mut body: Cow<Vec<u8>> = Cow::Borrowed(self.get_body());
-        if !unset_content_length {
+        let is_not_modified: bool = status_code == HttpStatus::NotModified.code();
+        if is_not_modified || status_code == HttpStatus::NoContent.code() {
+            if is_not_modified && let Some(content_length) = &content_length_opt {
+                Self::push_header(&mut response_string, CONTENT_LENGTH, content_length);
+            }
+        } else if is_chunked {
+            body = Cow::Owned(Self::encode_chunk(&body));
+        } else if !unset_content_length {
             if let Some(compress_type) = compress_type_opt {
                 if !compress_type.is_unknown() {
                     let tmp_body: Cow<'_, Vec<u8>> =
@@ -227,6 +509,81 @@ impl Response {
         response_bytes
     }
 
+    /// Encodes data as a single chunk of a chunked response body.
+    ///
+    /// Empty data yields no bytes, since a zero-sized chunk would terminate the body.
+    ///
+    /// # Parameters
+    /// - `data`: The chunk payload.
+    ///
+    /// # Returns
+    /// - `ResponseData`: The chunk size line, the payload and the closing CRLF.
+    pub fn encode_chunk(data: &[u8]) -> ResponseData {
+        if data.is_empty() {
+            return Vec::new();
+        }
+        let size_line: String = format!("{:x}{}", data.len(), HTTP_BR);
+        let mut chunk: ResponseData =
+            Vec::with_capacity(size_line.len() + data.len() + HTTP_BR_BYTES.len());
+        chunk.extend_from_slice(size_line.as_bytes());
+        chunk.extend_from_slice(data);
+        chunk.extend_from_slice(HTTP_BR_BYTES);
+        chunk
+    }
+
+    /// Encodes the last chunk of a chunked response body, followed by optional trailers.
+    ///
+    /// # Parameters
+    /// - `trailers`: Trailer headers sent after the last chunk.
+    ///
+    /// # Returns
+    /// - `ResponseData`: The zero-sized chunk, the trailer lines and the final CRLF.
+    pub fn encode_last_chunk(trailers: &ResponseTrailers) -> ResponseData {
+        let mut last_chunk: String = String::new();
+        last_chunk.push_str(ZERO_STR);
+        last_chunk.push_str(HTTP_BR);
+        for (key, value) in trailers.iter() {
+            Self::push_header(&mut last_chunk, key, value);
+        }
+        last_chunk.push_str(HTTP_BR);
+        last_chunk.into_bytes()
+    }
+
+    /// Checks whether the connection stays open after this response.
+    ///
+    /// A `Connection: keep-alive` header keeps it open and a `Connection: close`
+    /// header closes it. Without either, HTTP/1.1 and higher keep it open.
+    ///
+    /// # Returns
+    /// - `bool`: `true` if the connection stays open; otherwise, `false`.
+    pub fn is_enable_keep_alive(&self) -> bool {
+        if let Some(connection_value) = self.get_header(CONNECTION) {
+            if connection_value.eq_ignore_ascii_case(KEEP_ALIVE) {
+                return true;
+            } else if connection_value.eq_ignore_ascii_case(CLOSE) {
+                return false;
+            }
+        }
+        self.get_version().is_http1_1_or_higher()
+    }
+
+    /// Checks whether the response is an informational 1xx response.
+    ///
+    /// # Returns
+    /// - `bool`: `true` if the status code is between `100` and `199`; otherwise, `false`.
+    pub fn is_informational(&self) -> bool {
+        (100..200).contains(self.get_status_code())
+    }
+
+    /// Checks whether the response is sent with chunked transfer encoding.
+    ///
+    /// # Returns
+    /// - `bool`: `true` if the final transfer coding is `chunked`; otherwise, `false`.
+    pub fn is_chunked(&self) -> bool {
+        Request::transfer_encoding_from_headers(&self.headers)
+            .is_some_and(|value| Request::is_chunked_transfer_encoding(&value))
+    }
+
     /// Converts the response to a formatted string representation.
     ///
     /// - Returns: A `String` containing formatted response details.
@@ -1,3 +1,5 @@
+pub(crate) mod cfg;
+pub(crate) mod r#const;
 pub(crate) mod error;
 pub(crate) mod r#impl;
 pub(crate) mod r#struct;
@@ -6,3 +8,5 @@ pub(crate) mod r#type;
 pub use error::*;
 pub use r#struct::*;
 pub use r#type::*;
+
+pub(crate) use r#const::*;
@@ -12,6 +12,8 @@ pub struct Response {
     pub(super) reason_phrase: ResponseReasonPhrase,
     /// The response headers as key-value pairs.
     pub(super) headers: ResponseHeaders,
+    /// Trailer headers received after a chunked response body.
+    pub(super) trailers: ResponseTrailers,
     /// The binary body content of the response.
     #[set(skip)]
     pub(super) body: ResponseBody,
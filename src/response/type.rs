@@ -8,8 +8,10 @@ pub type ResponseBodyString = String;
 pub type ResponseHeadersKey = String;
 /// The value type used in HTTP response headers.
 pub type ResponseHeadersValue = String;
-/// A map of HTTP response headers.
-pub type ResponseHeaders = HashMapXxHash3_64<ResponseHeadersKey, ResponseHeadersValue>;
+/// A map of HTTP response headers, including repeated ones.
+pub type ResponseHeaders = HeaderMap;
+/// Trailer headers sent after the last chunk of a chunked response.
+pub type ResponseTrailers = HeaderMap;
 /// The HTTP version of the response (e.g., "HTTP/1.1").
 pub type ResponseVersion = HttpVersion;
 /// The numeric status code of the HTTP response (e.g., 200, 404).
@@ -28,3 +30,5 @@ pub type RwLockReadGuardResponse<'a> = RwLockReadGuard<'a, Response>;
 pub type RwLockWriteGuardResponse<'a> = RwLockWriteGuard<'a, Response>;
 /// An optional value of a response header.
 pub type OptionResponseHeadersValue = Option<ResponseHeadersValue>;
+/// The result type returned when reading an HTTP response.
+pub type ResponseReaderHandleResult = Result<Response, RequestError>;
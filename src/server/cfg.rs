@@ -0,0 +1,161 @@
+#[tokio::test]
+async fn test_http_connection_serve() {
+    use crate::*;
+    use tokio::net::TcpListener;
+    let listener: TcpListener = TcpListener::bind(SOCKET_ADDR_127_0_0_1).await.unwrap();
+    let mut client: TcpStream = TcpStream::connect(listener.local_addr().unwrap())
+        .await
+        .unwrap();
+    let (server, _) = listener.accept().await.unwrap();
+    let (shutdown_sender, shutdown_receiver) = tokio::sync::watch::channel(false);
+    let serving: tokio::task::JoinHandle<HttpConnectionResult> = tokio::spawn(async move {
+        let mut connection: HttpConnection =
+            HttpConnection::new(ArcRwLockStream::from_stream(server));
+        connection.set_shutdown(shutdown_receiver);
+        connection
+            .serve(|request: Request| async move {
+                let mut response: Response = Response::new();
+                response.set_body(format!(
+                    "{} {}",
+                    request.get_path(),
+                    request.get_body_string()
+                ));
+                response
+            })
+            .await
+    });
+    let mut reader: BufReader<&mut TcpStream> = BufReader::new(&mut client);
+    reader
+        .get_mut()
+        .write_all(b"GET /a HTTP/1.1\r\nHost: localhost\r\n\r\nHEAD /b HTTP/1.1\r\nHost: localhost\r\n\r\n")
+        .await
+        .unwrap();
+    let config: RequestConfig = RequestConfig::default();
+    let response: Response = Response::http_from_reader(&mut reader, &config, &Method::GET)
+        .await
+        .unwrap();
+    assert_eq!(response.get_body_string(), "/a ");
+    assert!(response.is_enable_keep_alive());
+    let response: Response = Response::http_from_reader(&mut reader, &config, &Method::HEAD)
+        .await
+        .unwrap();
+    assert_eq!(response.get_header(CONTENT_LENGTH), Some("3".to_string()));
+    assert!(response.get_body().is_empty());
+    reader
+        .get_mut()
+        .write_all(b"POST /c HTTP/1.1\r\nHost: localhost\r\nExpect: 100-continue\r\nContent-Length: 4\r\n\r\n")
+        .await
+        .unwrap();
+    let mut interim: String = String::new();
+    reader.read_line(&mut interim).await.unwrap();
+    assert_eq!(interim, "HTTP/1.1 100 Continue\r\n");
+    reader.read_line(&mut interim).await.unwrap();
+    reader.get_mut().write_all(b"data").await.unwrap();
+    let response: Response = Response::http_from_reader(&mut reader, &config, &Method::POST)
+        .await
+        .unwrap();
+    assert_eq!(response.get_body_string(), "/c data");
+    shutdown_sender.send(true).unwrap();
+    assert_eq!(serving.await.unwrap(), Ok(()));
+    let mut rest: Vec<u8> = Vec::new();
+    reader.read_to_end(&mut rest).await.unwrap();
+    assert!(rest.is_empty());
+}
+
+#[tokio::test]
+async fn test_http_connection_close() {
+    use crate::*;
+    let (client, server) = tokio::io::duplex(4096);
+    let serving: tokio::task::JoinHandle<HttpConnectionResult> = tokio::spawn(async move {
+        HttpConnection::new(ArcRwLockStream::from_stream(server))
+            .serve(|_: Request| async { Response::new() })
+            .await
+    });
+    let mut reader: BufReader<DuplexStream> = BufReader::new(client);
+    reader
+        .get_mut()
+        .write_all(b"GET / HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\nGET /ignored HTTP/1.1\r\n")
+        .await
+        .unwrap();
+    let response: Response =
+        Response::http_from_reader(&mut reader, &RequestConfig::default(), &Method::GET)
+            .await
+            .unwrap();
+    assert_eq!(response.get_header(CONNECTION), Some(CLOSE.to_string()));
+    assert_eq!(serving.await.unwrap(), Ok(()));
+    let (client, server) = tokio::io::duplex(4096);
+    let serving: tokio::task::JoinHandle<HttpConnectionResult> = tokio::spawn(async move {
+        HttpConnection::new(ArcRwLockStream::from_stream(server))
+            .serve(|_: Request| async { Response::new() })
+            .await
+    });
+    let mut reader: BufReader<DuplexStream> = BufReader::new(client);
+    reader
+        .get_mut()
+        .write_all(b"GET / HTTP/1.1\r\n\r\n")
+        .await
+        .unwrap();
+    let response: Response =
+        Response::http_from_reader(&mut reader, &RequestConfig::default(), &Method::GET)
+            .await
+            .unwrap();
+    assert_eq!(response.get_status_code(), &400);
+    assert_eq!(serving.await.unwrap(), Err(RequestError::MissingHost));
+}
+
+#[tokio::test]
+async fn test_http_connection_content_length_smuggling() {
+    use crate::*;
+    let requests: [&[u8]; 5] = [
+        b"POST /a HTTP/1.1\r\nHost: localhost\r\nContent-Length: abc\r\n\r\nGET /smuggled HTTP/1.1\r\nHost: localhost\r\n\r\n",
+        b"POST /a HTTP/1.1\r\nHost: localhost\r\nContent-Length: 0\r\nContent-Length: 30\r\n\r\nGET /smuggled HTTP/1.1\r\nHost: localhost\r\n\r\n",
+        b"POST /a HTTP/1.1\r\nHost: localhost\r\nTransfer-Encoding: chunked\r\nContent-Length: 3\r\n\r\n0\r\n\r\nGET /smuggled HTTP/1.1\r\nHost: localhost\r\n\r\n",
+        b"POST /a HTTP/1.1\r\nHost: localhost\r\nTransfer-Encoding: chunked\r\nTransfer-Encoding: gzip\r\n\r\n0\r\n\r\nGET /smuggled HTTP/1.1\r\nHost: localhost\r\n\r\n",
+        b"POST /a HTTP/1.1\r\nHost: localhost\r\nTransfer-Encoding: gzip\r\nTransfer-Encoding: chunked\r\nConnection: close\r\n\r\n0\r\n\r\nGET /smuggled HTTP/1.1\r\nHost: localhost\r\n\r\n",
+    ];
+    for (index, data) in requests.into_iter().enumerate() {
+        let (client, server) = tokio::io::duplex(4096);
+        let serving: tokio::task::JoinHandle<HttpConnectionResult> = tokio::spawn(async move {
+            HttpConnection::new(ArcRwLockStream::from_stream(server))
+                .serve(|request: Request| async move {
+                    let mut response: Response = Response::new();
+                    response.set_body(request.get_path().clone());
+                    response
+                })
+                .await
+        });
+        let mut reader: BufReader<DuplexStream> = BufReader::new(client);
+        reader.get_mut().write_all(data).await.unwrap();
+        let response: Response =
+            Response::http_from_reader(&mut reader, &RequestConfig::default(), &Method::POST)
+                .await
+                .unwrap();
+        assert_eq!(response.get_header(CONNECTION), Some(CLOSE.to_string()));
+        if index < 2 {
+            assert_eq!(response.get_status_code(), &400);
+            assert!(matches!(
+                serving.await.unwrap(),
+                Err(RequestError::InvalidContentLength(_))
+            ));
+        } else if index < 4 {
+            assert_eq!(response.get_status_code(), &400);
+            assert!(matches!(
+                serving.await.unwrap(),
+                Err(RequestError::InvalidTransferEncoding(_))
+            ));
+        } else {
+            assert_eq!(response.get_body_string(), "/a");
+            assert_eq!(serving.await.unwrap(), Ok(()));
+        }
+        let mut rest: Vec<u8> = Vec::new();
+        reader.read_to_end(&mut rest).await.unwrap();
+        assert!(rest.is_empty());
+    }
+    let headers: HeaderMap = {
+        let mut headers: HeaderMap = HeaderMap::new();
+        headers.append(CONTENT_LENGTH, "5, 5");
+        headers.append(CONTENT_LENGTH, "5");
+        headers
+    };
+    assert_eq!(Request::content_length_from_headers(&headers), Ok(Some(5)));
+}
@@ -0,0 +1,217 @@
+use crate::*;
+
+impl<S> Debug for HttpConnection<S>
+where
+    S: SplitStream,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpConnection")
+            .field("config", &self.config)
+            .field("shutdown", &self.is_shutdown())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S> HttpConnection<S>
+where
+    S: SplitStream,
+{
+    /// Creates a driver for a connection with the default limits and no shutdown signal.
+    ///
+    /// # Parameters
+    /// - `stream`: The connection, whose deadlines apply while reading and writing.
+    ///
+    /// # Returns
+    /// - A new `HttpConnection` instance.
+    pub fn new(stream: ArcRwLockStream<S>) -> Self {
+        Self {
+            stream,
+            config: RequestConfig::default(),
+            shutdown: None,
+        }
+    }
+
+    /// Retrieves the connection.
+    ///
+    /// # Returns
+    /// - `&ArcRwLockStream<S>`: The connection.
+    pub fn get_stream(&self) -> &ArcRwLockStream<S> {
+        &self.stream
+    }
+
+    /// Retrieves the buffer size and limits applied while reading requests.
+    ///
+    /// # Returns
+    /// - `&RequestConfig`: The limits.
+    pub fn get_config(&self) -> &RequestConfig {
+        &self.config
+    }
+
+    /// Sets the buffer size and limits applied while reading requests.
+    ///
+    /// # Parameters
+    /// - `config`: The limits.
+    ///
+    /// # Returns
+    /// - `&mut Self`: A reference to the driver, allowing for method chaining.
+    pub fn set_config(&mut self, config: RequestConfig) -> &mut Self {
+        self.config = config;
+        self
+    }
+
+    /// Sets the graceful shutdown signal.
+    ///
+    /// Once `true` is sent, an idle connection is closed right away, and a request
+    /// in progress is answered with `Connection: close` before the connection is closed.
+    ///
+    /// # Parameters
+    /// - `shutdown`: The receiving end of the shutdown signal.
+    ///
+    /// # Returns
+    /// - `&mut Self`: A reference to the driver, allowing for method chaining.
+    pub fn set_shutdown(&mut self, shutdown: ShutdownReceiver) -> &mut Self {
+        self.shutdown = Some(shutdown);
+        self
+    }
+
+    /// Checks whether the graceful shutdown has been signaled.
+    ///
+    /// # Returns
+    /// - `bool`: `true` if the shutdown has been signaled; otherwise, `false`.
+    pub fn is_shutdown(&self) -> bool {
+        self.shutdown
+            .as_ref()
+            .is_some_and(|shutdown| *shutdown.borrow())
+    }
+
+    /// Serves requests until the connection is closed.
+    ///
+    /// Each request is passed to the handler and its response is sent back. A
+    /// request expecting `100 Continue` is handled as configured by
+    /// `RequestConfig::expect_continue`. With `ExpectContinue::Manual`, the handler
+    /// receives it without its body and may read the body with `Request::accept_continue`
+    /// through a clone of the stream; the connection is then closed after the response,
+    /// since the body may be left unread. The body of a response to a `HEAD` request,
+    /// and of a `204` or `304` response, is not sent. The connection is kept alive unless the
+    /// request or the response asks to close it, and a `101 Switching Protocols`
+    /// response ends serving so the caller can take the connection over.
+    ///
+    /// A request that cannot be parsed is answered with the status returned by
+    /// `RequestError::get_http_status` and `Connection: close`.
+    ///
+    /// # Parameters
+    /// - `handler`: The function producing the response to a request.
+    ///
+    /// # Returns
+    /// - `Ok`: If the connection was closed by either side, stayed idle past its
+    ///   idle deadline, or the shutdown was signaled.
+    /// - `Err`: The `RequestError` of a request that could not be read, or
+    ///   `RequestError::Request` if a response could not be sent.
+    pub async fn serve<H, F>(&mut self, handler: H) -> HttpConnectionResult
+    where
+        H: Fn(Request) -> F,
+        F: Future<Output = Response>,
+    {
+        loop {
+            if !self.wait_request().await? {
+                return Ok(());
+            }
+            let request: Request =
+                match Request::http_request_from_stream(&self.stream, &self.config).await {
+                    Ok(request) => request,
+                    Err(err) => {
+                        if !matches!(
+                            err,
+                            RequestError::ClientDisconnected
+                                | RequestError::ConnectionReset
+                                | RequestError::Io(_)
+                        ) {
+                            let mut response: Response = Response::new();
+                            response
+                                .set_status_code(err.get_http_status().code())
+                                .set_header(CONNECTION, CLOSE);
+                            let _ = self.send_response(false, response).await;
+                        }
+                        return Err(err);
+                    }
+                };
+            let is_head: bool = request.is_head();
+            let is_keep_alive: bool = request.is_enable_keep_alive()
+                && !(request.is_expect_continue()
+                    && *self.config.get_expect_continue() == ExpectContinue::Manual);
+            let mut response: Response = handler(request).await;
+            let is_switching_protocols: bool =
+                *response.get_status_code() == HttpStatus::SwitchingProtocols.code();
+            let is_close: bool = !is_switching_protocols
+                && (!is_keep_alive || !response.is_enable_keep_alive() || self.is_shutdown());
+            if is_close {
+                response.set_header(CONNECTION, CLOSE);
+            }
+            self.send_response(is_head, response).await?;
+            if is_close || is_switching_protocols {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Waits until the next request starts to arrive.
+    ///
+    /// # Returns
+    /// - `Ok`: `true` if data is available, or `false` if the client closed the
+    ///   connection, the idle deadline elapsed or the shutdown was signaled.
+    /// - `Err`: `RequestError::ConnectionReset` or `RequestError::Io` if reading fails.
+    async fn wait_request(&mut self) -> Result<bool, RequestError> {
+        if self.is_shutdown() {
+            return Ok(false);
+        }
+        let idle_timeout: OptionDuration = *self.stream.get_timeouts().get_idle_timeout();
+        let stream: &ArcRwLockStream<S> = &self.stream;
+        let wait_data = async {
+            let mut reader: RwLockWriteGuardReadHalf<'_, S> = stream.read_half().await;
+            match timeout_option(idle_timeout, reader.fill_buf()).await {
+                Some(Ok(buffer)) => Ok(!buffer.is_empty()),
+                Some(Err(err)) => match RequestError::from_io_error(err) {
+                    RequestError::ClientDisconnected => Ok(false),
+                    err => Err(err),
+                },
+                None => Ok(false),
+            }
+        };
+        match &mut self.shutdown {
+            Some(shutdown) => tokio::select! {
+                result = wait_data => result,
+                Ok(_) = shutdown.wait_for(|is_shutdown| *is_shutdown) => Ok(false),
+            },
+            None => wait_data.await,
+        }
+    }
+
+    /// Sends a response, leaving out its body if the request or the status forbids one.
+    ///
+    /// # Parameters
+    /// - `is_head`: Whether the response answers a `HEAD` request.
+    /// - `response`: The response.
+    ///
+    /// # Returns
+    /// - `Ok`: If the response was sent.
+    /// - `Err`: `RequestError::Request` if sending or flushing fails.
+    async fn send_response(&self, is_head: bool, mut response: Response) -> HttpConnectionResult {
+        let mut data: ResponseData = response.build();
+        let status_code: ResponseStatusCode = *response.get_status_code();
+        if (is_head
+            || status_code == HttpStatus::NoContent.code()
+            || status_code == HttpStatus::NotModified.code())
+            && let Some(index) = find_bytes(&data, HTTP_DOUBLE_BR.as_bytes())
+        {
+            data.truncate(index + HTTP_DOUBLE_BR.len());
+        }
+        self.stream
+            .send(&data)
+            .await
+            .map_err(|err| RequestError::Request(err.to_string()))?;
+        self.stream
+            .try_flush()
+            .await
+            .map_err(|err| RequestError::Request(err.to_string()))
+    }
+}
@@ -0,0 +1,7 @@
+pub(crate) mod cfg;
+pub(crate) mod r#impl;
+pub(crate) mod r#struct;
+pub(crate) mod r#type;
+
+pub use r#struct::*;
+pub use r#type::*;
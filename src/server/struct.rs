@@ -0,0 +1,16 @@
+use crate::*;
+
+/// Drives the HTTP/1.1 request and response exchanges of a server connection.
+///
+/// Requests are read and passed to a handler until the client or the handler
+/// closes the connection, the connection stays idle, or a graceful shutdown is
+/// signaled. Keep-alive, `HEAD` body suppression and `Expect: 100-continue` are
+/// handled by the driver.
+pub struct HttpConnection<S: SplitStream = TcpStream> {
+    /// The connection.
+    pub(super) stream: ArcRwLockStream<S>,
+    /// The buffer size and limits applied while reading requests.
+    pub(super) config: RequestConfig,
+    /// The graceful shutdown signal.
+    pub(super) shutdown: OptionShutdownReceiver,
+}
@@ -0,0 +1,8 @@
+use crate::*;
+
+/// Receives the graceful shutdown signal of a server, `true` requesting the shutdown.
+pub type ShutdownReceiver = tokio::sync::watch::Receiver<bool>;
+/// An optional graceful shutdown signal.
+pub type OptionShutdownReceiver = Option<ShutdownReceiver>;
+/// The result type returned once a connection has been served.
+pub type HttpConnectionResult = Result<(), RequestError>;
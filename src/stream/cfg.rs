@@ -0,0 +1,219 @@
+#[tokio::test]
+async fn test_arc_rwlock_stream_duplex() {
+    use crate::*;
+    use tokio::io::DuplexStream;
+    let (mut client, server) = tokio::io::duplex(1024);
+    client
+        .write_all(b"POST /echo HTTP/1.1\r\nHost: localhost\r\nContent-Length: 5\r\n\r\nhello")
+        .await
+        .unwrap();
+    let stream: ArcRwLockStream<DuplexStream> = ArcRwLockStream::from_stream(server);
+    let request: Request = Request::http_request_from_stream(&stream, &RequestConfig::default())
+        .await
+        .unwrap();
+    assert_eq!(request.get_path(), "/echo");
+    let mut response: Response = Response::default();
+    response.set_body(request.get_body().clone());
+    stream.send(&response.build()).await.unwrap();
+    drop(stream);
+    let mut data: String = String::new();
+    client.read_to_string(&mut data).await.unwrap();
+    assert!(data.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(data.ends_with("\r\n\r\nhello"));
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_arc_rwlock_unix_stream() {
+    use crate::*;
+    let (mut client, server) = UnixStream::pair().unwrap();
+    client
+        .write_all(b"GET /health HTTP/1.1\r\nHost: localhost\r\n\r\n")
+        .await
+        .unwrap();
+    let stream: ArcRwLockUnixStream = ArcRwLockStream::from_stream(server);
+    let request: Request = Request::http_request_from_stream(&stream, &RequestConfig::default())
+        .await
+        .unwrap();
+    assert_eq!(request.get_path(), "/health");
+    let cred: UCred = stream.peer_cred().await.unwrap();
+    assert!(
+        cred.pid()
+            .is_none_or(|pid| pid == std::process::id() as i32)
+    );
+    stream.send_body(&b"ok".to_vec()).await.unwrap();
+    drop(stream);
+    let mut data: Vec<u8> = Vec::new();
+    client.read_to_end(&mut data).await.unwrap();
+    assert_eq!(data, b"ok");
+}
+
+#[tokio::test]
+async fn test_arc_rwlock_stream_write_while_reading() {
+    use crate::*;
+    use tokio::{net::TcpListener, task::JoinHandle};
+    let listener: TcpListener = TcpListener::bind(SOCKET_ADDR_127_0_0_1).await.unwrap();
+    let mut client: TcpStream = TcpStream::connect(listener.local_addr().unwrap())
+        .await
+        .unwrap();
+    let (server, _) = listener.accept().await.unwrap();
+    let stream: ArcRwLockStream = ArcRwLockStream::from_stream(server);
+    assert_eq!(stream.local_addr().await, client.peer_addr().ok());
+    assert_eq!(stream.peer_addr().await, client.local_addr().ok());
+    let reading_stream: ArcRwLockStream = stream.clone();
+    let reading: JoinHandle<RequestReaderHandleResult> = tokio::spawn(async move {
+        Request::http_request_from_stream(&reading_stream, &RequestConfig::default()).await
+    });
+    tokio::task::yield_now().await;
+    tokio::time::timeout(Duration::from_secs(1), stream.send_body(&b"push".to_vec()))
+        .await
+        .unwrap()
+        .unwrap();
+    let mut pushed: [u8; 4] = [0; 4];
+    client.read_exact(&mut pushed).await.unwrap();
+    assert_eq!(&pushed, b"push");
+    client
+        .write_all(b"GET /late HTTP/1.1\r\nHost: localhost\r\n\r\n")
+        .await
+        .unwrap();
+    let request: Request = reading.await.unwrap().unwrap();
+    assert_eq!(request.get_path(), "/late");
+}
+
+#[tokio::test]
+async fn test_arc_rwlock_stream_keeps_buffered_bytes() {
+    use crate::*;
+    use tokio::io::DuplexStream;
+    let (mut client, server) = tokio::io::duplex(1024);
+    let mut data: Vec<u8> = b"GET /first HTTP/1.1\r\nHost: localhost\r\n\r\n\
+        GET /chat HTTP/1.1\r\nHost: localhost\r\nUpgrade: websocket\r\n\r\n"
+        .to_vec();
+    for frame in WebSocketFrame::create_response_frame_list(&b"one".to_vec()) {
+        data.extend_from_slice(&frame);
+    }
+    for frame in WebSocketFrame::create_response_frame_list(&b"two".to_vec()) {
+        data.extend_from_slice(&frame);
+    }
+    client.write_all(&data).await.unwrap();
+    let stream: ArcRwLockStream<DuplexStream> = ArcRwLockStream::from_stream(server);
+    let config: RequestConfig = RequestConfig::default();
+    let first: Request = Request::http_request_from_stream(&stream, &config)
+        .await
+        .unwrap();
+    assert_eq!(first.get_path(), "/first");
+    let mut upgrade: Request = Request::http_request_from_stream(&stream, &config)
+        .await
+        .unwrap();
+    assert_eq!(upgrade.get_path(), "/chat");
+    let message: Request = Request::ws_request_from_stream(&stream, &config, &mut upgrade)
+        .await
+        .unwrap();
+    assert_eq!(message.get_body(), b"one");
+    let message: Request = Request::ws_request_from_stream(&stream, &config, &mut upgrade)
+        .await
+        .unwrap();
+    assert_eq!(message.get_body(), b"two");
+}
+
+#[tokio::test]
+async fn test_arc_rwlock_stream_timeouts() {
+    use crate::*;
+    use tokio::io::DuplexStream;
+    let mut timeouts: StreamTimeouts = StreamTimeouts::new();
+    assert!(matches!(
+        timeouts.set_idle_timeout(Some(Duration::ZERO)),
+        Err(RequestError::SetReadTimeout(_))
+    ));
+    assert!(matches!(
+        timeouts.set_write_timeout(Some(Duration::ZERO)),
+        Err(RequestError::SetWriteTimeout(_))
+    ));
+    timeouts
+        .set_idle_timeout(Some(Duration::from_millis(50)))
+        .unwrap()
+        .set_header_read_timeout(Some(Duration::from_millis(50)))
+        .unwrap()
+        .set_write_timeout(Some(Duration::from_millis(50)))
+        .unwrap();
+    let (mut client, server) = tokio::io::duplex(16);
+    let mut stream: ArcRwLockStream<DuplexStream> = ArcRwLockStream::from_stream(server);
+    stream.set_timeouts(timeouts);
+    let config: RequestConfig = RequestConfig::default();
+    let idle: RequestError = Request::http_request_from_stream(&stream, &config)
+        .await
+        .unwrap_err();
+    assert_eq!(idle, RequestError::RequestTimeout);
+    assert_eq!(idle.get_http_status(), HttpStatus::RequestTimeout);
+    client.write_all(b"GET / HTTP/1.1\r\n").await.unwrap();
+    assert_eq!(
+        Request::http_request_from_stream(&stream, &config)
+            .await
+            .unwrap_err(),
+        RequestError::RequestTimeout
+    );
+    assert!(matches!(
+        stream.send_body(&vec![0; 64]).await,
+        Err(ResponseError::WriteTimeout)
+    ));
+}
+
+#[tokio::test]
+async fn test_arc_rwlock_stream_interim_responses() {
+    use crate::*;
+    let (client, server) = tokio::io::duplex(4096);
+    let stream: ArcRwLockStream<DuplexStream> = ArcRwLockStream::from_stream(server);
+    stream
+        .send_early_hints(&[
+            "</style.css>; rel=preload; as=style",
+            "</app.js>; rel=preload",
+        ])
+        .await
+        .unwrap();
+    stream.send_continue().await.unwrap();
+    let mut ok: Response = Response::new();
+    assert!(matches!(
+        stream.send_interim(&mut ok).await,
+        Err(ResponseError::NotInterimStatus(200))
+    ));
+    ok.set_body("done");
+    stream.send(&ok.build()).await.unwrap();
+    drop(stream);
+    let mut reader: BufReader<DuplexStream> = BufReader::new(client);
+    let mut head: String = String::new();
+    for _ in 0..4 {
+        reader.read_line(&mut head).await.unwrap();
+    }
+    assert_eq!(
+        head,
+        "HTTP/1.1 103 Early Hints\r\nlink: </style.css>; rel=preload; as=style\r\nlink: </app.js>; rel=preload\r\n\r\n"
+    );
+    let config: RequestConfig = RequestConfig::default();
+    let interim: Response = Response::http_from_reader(&mut reader, &config, &Method::GET)
+        .await
+        .unwrap();
+    assert_eq!(interim.get_status_code(), &100);
+    assert!(interim.get_headers().is_empty());
+    let response: Response = Response::http_from_reader(&mut reader, &config, &Method::GET)
+        .await
+        .unwrap();
+    assert_eq!(response.get_body_string(), "done");
+}
+
+#[tokio::test]
+#[allow(deprecated)]
+async fn test_arc_rwlock_stream_deprecated_shims() {
+    use crate::*;
+    let (mut client, server) = tokio::io::duplex(1024);
+    let shared: ArcRwLock<DuplexStream> = arc_rwlock(server);
+    let other: ArcRwLock<DuplexStream> = Arc::clone(&shared);
+    let shared: ArcRwLock<DuplexStream> = ArcRwLockStream::from(shared).unwrap_err();
+    drop(other);
+    let stream: ArcRwLockStream<DuplexStream> = ArcRwLockStream::from(shared).unwrap();
+    stream.write().await.write_all(b"ping").await.unwrap();
+    client.write_all(b"pong").await.unwrap();
+    let mut data: Vec<u8> = vec![0; 4];
+    stream.read().await.read_exact(&mut data).await.unwrap();
+    assert_eq!(data, b"pong");
+    client.read_exact(&mut data).await.unwrap();
+    assert_eq!(data, b"ping");
+}
@@ -0,0 +1,2 @@
+/// Reason reported when a timeout is set to zero.
+pub(crate) const ZERO_TIMEOUT: &str = "timeout must be greater than zero";
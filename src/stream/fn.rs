@@ -0,0 +1,20 @@
+use crate::*;
+
+/// Awaits a future, giving up once an optional deadline elapses.
+///
+/// # Parameters
+/// - `duration`: The deadline, or `None` to wait without limit.
+/// - `future`: The future to await.
+///
+/// # Returns
+/// - `Some`: The output of the future if it completed in time.
+/// - `None`: If the deadline elapsed first.
+pub(crate) async fn timeout_option<F>(duration: OptionDuration, future: F) -> Option<F::Output>
+where
+    F: Future,
+{
+    match duration {
+        Some(duration) => tokio::time::timeout(duration, future).await.ok(),
+        None => Some(future.await),
+    }
+}
@@ -1,51 +1,259 @@
 use crate::*;
 
-impl ArcRwLockStream {
-    /// Creates a new `ArcRwLockStream` from an `Arc<RwLock<TcpStream>>`.
+impl<T: AsyncRead + AsyncWrite + Unpin> AsyncReadWrite for T {}
+
+impl SplitStream for TcpStream {
+    type ReadHalf = tokio::net::tcp::OwnedReadHalf;
+    type WriteHalf = tokio::net::tcp::OwnedWriteHalf;
+
+    fn split_stream(self) -> (Self::ReadHalf, Self::WriteHalf) {
+        self.into_split()
+    }
+}
+
+#[cfg(unix)]
+impl SplitStream for UnixStream {
+    type ReadHalf = tokio::net::unix::OwnedReadHalf;
+    type WriteHalf = tokio::net::unix::OwnedWriteHalf;
+
+    fn split_stream(self) -> (Self::ReadHalf, Self::WriteHalf) {
+        self.into_split()
+    }
+}
+
+impl SplitStream for DuplexStream {
+    type ReadHalf = ReadHalf<DuplexStream>;
+    type WriteHalf = WriteHalf<DuplexStream>;
+
+    fn split_stream(self) -> (Self::ReadHalf, Self::WriteHalf) {
+        tokio::io::split(self)
+    }
+}
+
+impl StreamTimeouts {
+    /// Creates a new `StreamTimeouts` with every deadline disabled.
+    ///
+    /// # Returns
+    /// - An initialized `StreamTimeouts` without deadlines.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum time to wait for the first byte of a request on an idle connection.
+    ///
+    /// # Parameters
+    /// - `timeout`: The deadline, or `None` to wait without limit.
+    ///
+    /// # Returns
+    /// - `Ok`: A reference to the timeouts, allowing for method chaining.
+    /// - `Err`: `RequestError::SetReadTimeout` if the deadline is zero.
+    pub fn set_idle_timeout(&mut self, timeout: OptionDuration) -> Result<&mut Self, RequestError> {
+        self.idle_timeout = Self::validate(timeout)
+            .ok_or_else(|| RequestError::SetReadTimeout(ZERO_TIMEOUT.to_string()))?;
+        Ok(self)
+    }
+
+    /// Sets the maximum time to read the request line and headers once the first byte arrived.
+    ///
+    /// # Parameters
+    /// - `timeout`: The deadline, or `None` to wait without limit.
+    ///
+    /// # Returns
+    /// - `Ok`: A reference to the timeouts, allowing for method chaining.
+    /// - `Err`: `RequestError::SetReadTimeout` if the deadline is zero.
+    pub fn set_header_read_timeout(
+        &mut self,
+        timeout: OptionDuration,
+    ) -> Result<&mut Self, RequestError> {
+        self.header_read_timeout = Self::validate(timeout)
+            .ok_or_else(|| RequestError::SetReadTimeout(ZERO_TIMEOUT.to_string()))?;
+        Ok(self)
+    }
+
+    /// Sets the maximum time to read the request body.
+    ///
+    /// # Parameters
+    /// - `timeout`: The deadline, or `None` to wait without limit.
+    ///
+    /// # Returns
+    /// - `Ok`: A reference to the timeouts, allowing for method chaining.
+    /// - `Err`: `RequestError::SetReadTimeout` if the deadline is zero.
+    pub fn set_body_read_timeout(
+        &mut self,
+        timeout: OptionDuration,
+    ) -> Result<&mut Self, RequestError> {
+        self.body_read_timeout = Self::validate(timeout)
+            .ok_or_else(|| RequestError::SetReadTimeout(ZERO_TIMEOUT.to_string()))?;
+        Ok(self)
+    }
+
+    /// Sets the maximum time to write a response or a response body.
+    ///
+    /// # Parameters
+    /// - `timeout`: The deadline, or `None` to wait without limit.
+    ///
+    /// # Returns
+    /// - `Ok`: A reference to the timeouts, allowing for method chaining.
+    /// - `Err`: `RequestError::SetWriteTimeout` if the deadline is zero.
+    pub fn set_write_timeout(
+        &mut self,
+        timeout: OptionDuration,
+    ) -> Result<&mut Self, RequestError> {
+        self.write_timeout = Self::validate(timeout)
+            .ok_or_else(|| RequestError::SetWriteTimeout(ZERO_TIMEOUT.to_string()))?;
+        Ok(self)
+    }
+
+    /// Rejects a zero deadline, which would expire before any I/O could complete.
     ///
     /// # Parameters
-    /// - `arc_rw_lock_stream`: An `Arc<RwLock<TcpStream>>` that will be wrapped in the new `ArcRwLockStream`
+    /// - `timeout`: The deadline to check.
     ///
     /// # Returns
-    /// Returns a new `ArcRwLockStream` instance containing the provided stream
-    pub fn from(arc_rw_lock_stream: ArcRwLock<TcpStream>) -> Self {
-        Self(arc_rw_lock_stream)
+    /// - `Some`: The deadline if it is unset or greater than zero.
+    /// - `None`: If the deadline is zero.
+    fn validate(timeout: OptionDuration) -> Option<OptionDuration> {
+        match timeout {
+            Some(duration) if duration.is_zero() => None,
+            _ => Some(timeout),
+        }
+    }
+}
+
+impl<S> Clone for ArcRwLockStream<S>
+where
+    S: SplitStream,
+{
+    fn clone(&self) -> Self {
+        Self {
+            read_half: Arc::clone(&self.read_half),
+            write_half: Arc::clone(&self.write_half),
+            timeouts: self.timeouts,
+        }
     }
+}
+
+impl<S> Debug for ArcRwLockStream<S>
+where
+    S: SplitStream,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArcRwLockStream").finish_non_exhaustive()
+    }
+}
 
-    /// Creates a new `ArcRwLockStream` from a `TcpStream`.
+impl<S> ArcRwLockStream<S>
+where
+    S: SplitStream,
+{
+    /// Creates a new `ArcRwLockStream` from an already split read half and write half.
     ///
     /// # Parameters
-    /// - `stream`: A `TcpStream` that will be wrapped in the new `ArcRwLockStream`
+    /// - `read_half`: The read half of the stream.
+    /// - `write_half`: The write half of the stream.
     ///
     /// # Returns
-    /// Returns a new `ArcRwLockStream` instance containing the provided stream wrapped in an `Arc<RwLock<_>>`
-    pub fn from_stream(stream: TcpStream) -> Self {
-        Self(arc_rwlock(stream))
+    /// Returns a new `ArcRwLockStream` instance buffering the read half and locking each half independently
+    pub fn from_halves(read_half: S::ReadHalf, write_half: S::WriteHalf) -> Self {
+        Self {
+            read_half: arc_rwlock(BufReader::new(read_half)),
+            write_half: arc_rwlock(write_half),
+            timeouts: StreamTimeouts::default(),
+        }
     }
 
-    /// Returns a reference to the inner `TcpStream`.
+    /// Creates a new `ArcRwLockStream` from a stream such as a `TcpStream`.
     ///
-    /// This method acquires a read lock on the underlying stream, allowing shared access
-    /// to the TCP stream while preventing concurrent writes.
+    /// # Parameters
+    /// - `stream`: A stream that will be split and wrapped in the new `ArcRwLockStream`
     ///
     /// # Returns
-    /// Returns a read guard that provides shared access to the TCP stream
-    pub async fn read(&self) -> RwLockReadGuardTcpStream {
-        self.0.read().await
+    /// Returns a new `ArcRwLockStream` instance with each half of the stream wrapped in an `Arc<RwLock<_>>`
+    pub fn from_stream(stream: S) -> Self {
+        let (read_half, write_half) = stream.split_stream();
+        Self::from_halves(read_half, write_half)
     }
 
-    /// Returns a mutable reference to the inner `TcpStream`.
+    /// Creates a new `ArcRwLockStream` from an `Arc<RwLock<S>>`.
+    ///
+    /// The stream is taken out of the lock and split, so the lock must not be shared.
+    ///
+    /// # Parameters
+    /// - `arc_rw_lock_stream`: An `Arc<RwLock<S>>` holding the only reference to the stream.
     ///
-    /// This method acquires a write lock on the underlying stream, allowing exclusive access
-    /// for writing operations while preventing any concurrent access.
+    /// # Returns
+    /// - `Ok`: A new `ArcRwLockStream` instance with each half of the stream wrapped in an `Arc<RwLock<_>>`.
+    /// - `Err`: The `Arc<RwLock<S>>` given back unchanged if another reference to it exists.
+    #[deprecated(note = "use `ArcRwLockStream::from_stream` instead")]
+    pub fn from(arc_rw_lock_stream: ArcRwLock<S>) -> Result<Self, ArcRwLock<S>> {
+        let stream: RwLock<S> = Arc::try_unwrap(arc_rw_lock_stream)?;
+        Ok(Self::from_stream(stream.into_inner()))
+    }
+
+    /// Returns the deadlines applied while reading and writing through this handle.
     ///
     /// # Returns
-    /// Returns a write guard that provides exclusive access to the TCP stream
-    pub(crate) async fn write(&self) -> RwLockWriteGuardTcpStream {
-        self.0.write().await
+    /// - `StreamTimeouts`: The configured deadlines.
+    pub fn get_timeouts(&self) -> StreamTimeouts {
+        self.timeouts
     }
 
-    /// Sends the HTTP response over a TCP stream.
+    /// Sets the deadlines applied while reading and writing through this handle.
+    ///
+    /// Clones made afterwards inherit the deadlines, while existing clones keep their own.
+    ///
+    /// # Parameters
+    /// - `timeouts`: The deadlines to apply.
+    ///
+    /// # Returns
+    /// - `&mut Self`: A reference to the stream, allowing for method chaining.
+    pub fn set_timeouts(&mut self, timeouts: StreamTimeouts) -> &mut Self {
+        self.timeouts = timeouts;
+        self
+    }
+
+    /// Returns a mutable reference to the buffered read half of the stream.
+    ///
+    /// This method acquires the lock of the read half only, so writes through
+    /// the write half are not blocked while a read is pending. Bytes left in the
+    /// buffer by a previous read are returned first.
+    ///
+    /// # Returns
+    /// Returns a write guard that provides exclusive access to the buffered read half
+    pub async fn read_half(&self) -> RwLockWriteGuardReadHalf<'_, S> {
+        self.read_half.write().await
+    }
+
+    /// Returns a mutable reference to the write half of the stream.
+    ///
+    /// This method acquires the lock of the write half only, so a pending read
+    /// through the read half does not block it.
+    ///
+    /// # Returns
+    /// Returns a write guard that provides exclusive access to the write half
+    pub async fn write_half(&self) -> RwLockWriteGuardWriteHalf<'_, S> {
+        self.write_half.write().await
+    }
+
+    /// Returns a mutable reference to the buffered read half of the stream.
+    ///
+    /// # Returns
+    /// Returns a write guard that provides exclusive access to the buffered read half
+    #[deprecated(note = "use `ArcRwLockStream::read_half` instead")]
+    pub async fn read(&self) -> RwLockWriteGuardReadHalf<'_, S> {
+        self.read_half().await
+    }
+
+    /// Returns a mutable reference to the write half of the stream.
+    ///
+    /// # Returns
+    /// Returns a write guard that provides exclusive access to the write half
+    #[deprecated(note = "use `ArcRwLockStream::write_half` instead")]
+    pub async fn write(&self) -> RwLockWriteGuardWriteHalf<'_, S> {
+        self.write_half().await
+    }
+
+    /// Sends the HTTP response over the stream.
     ///
     /// # Parameters
     /// - `data`: Response data
@@ -54,15 +262,66 @@ impl ArcRwLockStream {
     /// - `Ok`: If the response is successfully sent.
     /// - `Err`: If an error occurs during sending.
     pub async fn send(&self, data: &ResponseData) -> ResponseResult {
-        self.write()
+        let mut stream: RwLockWriteGuardWriteHalf<'_, S> = self.write_half().await;
+        timeout_option(self.timeouts.write_timeout, stream.write_all(data))
             .await
-            .write_all(&data)
-            .await
-            .map_err(|err| ResponseError::Response(err.to_string()))?;
-        Ok(())
+            .ok_or(ResponseError::WriteTimeout)?
+            .map_err(|err| ResponseError::Response(err.to_string()))
+    }
+
+    /// Sends an interim 1xx response over the stream and flushes it.
+    ///
+    /// Any number of interim responses may precede the final response.
+    ///
+    /// # Parameters
+    /// - `response`: The interim response, whose headers are sent without a body.
+    ///
+    /// # Returns
+    /// - `Ok`: If the interim response is successfully sent.
+    /// - `Err`: `ResponseError::NotInterimStatus` if the status code is not informational
+    ///   or is `101 Switching Protocols`, or another error if sending fails.
+    pub async fn send_interim(&self, response: &mut Response) -> ResponseResult {
+        let status_code: ResponseStatusCode = *response.get_status_code();
+        if !response.is_informational() || status_code == HttpStatus::SwitchingProtocols.code() {
+            return Err(ResponseError::NotInterimStatus(status_code));
+        }
+        self.send(&response.build()).await?;
+        self.try_flush().await
+    }
+
+    /// Sends a `100 Continue` interim response over the stream and flushes it.
+    ///
+    /// # Returns
+    /// - `Ok`: If the interim response is successfully sent.
+    /// - `Err`: If an error occurs during sending.
+    pub async fn send_continue(&self) -> ResponseResult {
+        let mut response: Response = Response::new();
+        response.set_status_code(HttpStatus::Continue.code());
+        self.send_interim(&mut response).await
+    }
+
+    /// Sends a `103 Early Hints` interim response over the stream and flushes it.
+    ///
+    /// # Parameters
+    /// - `links`: The `Link` header values, e.g. `</style.css>; rel=preload; as=style`,
+    ///   each sent as a separate header.
+    ///
+    /// # Returns
+    /// - `Ok`: If the interim response is successfully sent.
+    /// - `Err`: If an error occurs during sending.
+    pub async fn send_early_hints<L>(&self, links: &[L]) -> ResponseResult
+    where
+        L: AsRef<str>,
+    {
+        let mut response: Response = Response::new();
+        response.set_status_code(HttpStatus::EarlyHints.code());
+        for link in links {
+            response.append_header(LINK, link.as_ref());
+        }
+        self.send_interim(&mut response).await
     }
 
-    /// Sends the HTTP or HTTP websocket response body over a TCP stream.
+    /// Sends the HTTP or HTTP websocket response body over the stream.
     ///
     /// # Parameters
     /// - `body`: Response body.
@@ -81,17 +340,21 @@ impl ArcRwLockStream {
         } else {
             vec![body.clone()]
         };
-        let mut stream: RwLockWriteGuardTcpStream = self.write().await;
-        for tmp_body in body_list {
-            stream
-                .write_all(&tmp_body)
-                .await
-                .map_err(|err| ResponseError::Response(err.to_string()))?;
-        }
-        Ok(())
+        let mut stream: RwLockWriteGuardWriteHalf<'_, S> = self.write_half().await;
+        timeout_option(self.timeouts.write_timeout, async {
+            for tmp_body in body_list {
+                stream
+                    .write_all(&tmp_body)
+                    .await
+                    .map_err(|err| ResponseError::Response(err.to_string()))?;
+            }
+            Ok(())
+        })
+        .await
+        .ok_or(ResponseError::WriteTimeout)?
     }
 
-    /// Sends the HTTP response body over a TCP stream.
+    /// Sends the HTTP response body over the stream.
     ///
     /// # Parameters
     /// - `body`: Response body.
@@ -103,7 +366,7 @@ impl ArcRwLockStream {
         self.send_body_conditional(body, false).await
     }
 
-    /// Sends the HTTP ws response body over a TCP stream.
+    /// Sends the HTTP ws response body over the stream.
     ///
     /// # Parameters
     /// - `body`: Response body.
@@ -115,11 +378,86 @@ impl ArcRwLockStream {
         self.send_body_conditional(body, true).await
     }
 
-    /// Flush the TCP stream.
+    /// Sends a chunk of a chunked HTTP response body over the stream.
+    ///
+    /// Empty data is skipped, since a zero-sized chunk would terminate the body.
     ///
-    /// - Returns: A `ResponseResult` indicating success or failure.
+    /// # Parameters
+    /// - `data`: Chunk payload.
+    ///
+    /// # Returns
+    /// - `Ok`: If the chunk is successfully sent.
+    /// - `Err`: If an error occurs during sending.
+    pub async fn send_chunk(&self, data: &ResponseBody) -> ResponseResult {
+        if data.is_empty() {
+            return Ok(());
+        }
+        self.send(&Response::encode_chunk(data)).await
+    }
+
+    /// Sends the last chunk of a chunked HTTP response body, followed by trailers, over the stream.
+    ///
+    /// # Parameters
+    /// - `trailers`: Trailer headers sent after the last chunk.
+    ///
+    /// # Returns
+    /// - `Ok`: If the last chunk is successfully sent.
+    /// - `Err`: If an error occurs during sending.
+    pub async fn send_last_chunk(&self, trailers: &ResponseTrailers) -> ResponseResult {
+        self.send(&Response::encode_last_chunk(trailers)).await
+    }
+
+    /// Flushes the stream, reporting a failure.
+    ///
+    /// # Returns
+    /// - `Ok`: If the stream is successfully flushed.
+    /// - `Err`: `ResponseError::WriteTimeout` if the write deadline elapses, or
+    ///   `ResponseError::Response` if flushing fails.
+    pub(crate) async fn try_flush(&self) -> ResponseResult {
+        let mut stream: RwLockWriteGuardWriteHalf<'_, S> = self.write_half().await;
+        timeout_option(self.timeouts.write_timeout, stream.flush())
+            .await
+            .ok_or(ResponseError::WriteTimeout)?
+            .map_err(|err| ResponseError::Response(err.to_string()))
+    }
+
+    /// Flush the stream.
+    ///
+    /// Buffering transports such as TLS streams only send written data once flushed.
+    ///
+    /// - Returns: A reference to the stream, allowing for method chaining.
     pub async fn flush(&self) -> &Self {
-        let _ = self.write().await.flush();
+        let _ = self.write_half().await.flush().await;
         self
     }
 }
+
+impl ArcRwLockStream<TcpStream> {
+    /// Returns the address of the remote end of the connection.
+    ///
+    /// # Returns
+    /// - `OptionSocketAddr`: The peer address, or `None` if it cannot be read.
+    pub async fn peer_addr(&self) -> OptionSocketAddr {
+        self.write_half().await.peer_addr().ok()
+    }
+
+    /// Returns the local address of the connection.
+    ///
+    /// # Returns
+    /// - `OptionSocketAddr`: The local address, or `None` if it cannot be read.
+    pub async fn local_addr(&self) -> OptionSocketAddr {
+        self.write_half().await.local_addr().ok()
+    }
+}
+
+#[cfg(unix)]
+impl ArcRwLockStream<UnixStream> {
+    /// Returns the credentials of the process on the other end of the Unix domain socket.
+    ///
+    /// # Returns
+    /// - `Ok`: The user id, group id and, where the OS provides it, the process id of the peer.
+    /// - `Err`: If the OS does not support peer credentials or they cannot be read.
+    pub async fn peer_cred(&self) -> UCredResult {
+        self.write_half().await.as_ref().peer_cred()
+    }
+}
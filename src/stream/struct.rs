@@ -1,12 +1,38 @@
 use crate::*;
 
-/// A wrapper around `Arc<RwLock<TcpStream>>`.
+/// A stream split into a read half and a write half, each behind its own `Arc<RwLock<_>>`.
 ///
-/// `ArcRwLockStream` provides shared, thread-safe access to a `TcpStream`
-/// using an atomic reference counter (`Arc`) combined with a read-write lock (`RwLock`).
-/// It is primarily used to safely share the stream across asynchronous tasks.
+/// `ArcRwLockStream` provides shared, thread-safe access to a stream such as a
+/// `TcpStream` across asynchronous tasks. Since the halves are locked independently,
+/// a task can keep sending WebSocket or SSE messages while another task waits for
+/// incoming data. Any transport implementing `SplitStream` can be wrapped, and
+/// `TcpStream` is the default. The read half is buffered for the whole lifetime of
+/// the stream, so pipelined requests and frames sent right after a WebSocket
+/// handshake are kept for the next read.
 ///
 /// # Fields
-/// - `0`: The inner `Arc<RwLock<TcpStream>>` stream.
-#[derive(Clone, Debug)]
-pub struct ArcRwLockStream(pub(super) ArcRwLock<TcpStream>);
+/// - `read_half`: The buffered read half of the stream.
+/// - `write_half`: The write half of the stream.
+/// - `timeouts`: The deadlines applied while reading and writing through this handle.
+pub struct ArcRwLockStream<S: SplitStream = TcpStream> {
+    pub(super) read_half: ArcRwLock<BufReader<S::ReadHalf>>,
+    pub(super) write_half: ArcRwLock<S::WriteHalf>,
+    pub(super) timeouts: StreamTimeouts,
+}
+
+/// Deadlines applied while reading requests from and writing responses to a stream.
+///
+/// Every deadline is disabled by default. A request exceeding a read deadline fails
+/// with `RequestError::RequestTimeout`, and a write exceeding the write deadline
+/// fails with `ResponseError::WriteTimeout`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Getter, DisplayDebug)]
+pub struct StreamTimeouts {
+    /// The maximum time to wait for the first byte of a request on an idle connection.
+    pub(super) idle_timeout: OptionDuration,
+    /// The maximum time to read the request line and headers once the first byte arrived.
+    pub(super) header_read_timeout: OptionDuration,
+    /// The maximum time to read the request body.
+    pub(super) body_read_timeout: OptionDuration,
+    /// The maximum time to write a response or a response body.
+    pub(super) write_timeout: OptionDuration,
+}
@@ -0,0 +1,22 @@
+use crate::*;
+
+/// A bidirectional asynchronous transport, such as a `TcpStream`, a `UnixStream`,
+/// a TLS stream or an in-memory `DuplexStream`.
+pub trait AsyncReadWrite: AsyncRead + AsyncWrite + Unpin {}
+
+/// A transport that can be split into a read half and a write half used independently.
+///
+/// Sockets split into owned halves without any locking, while transports sharing
+/// state between directions, such as TLS streams, are split with `tokio::io::split`.
+pub trait SplitStream: AsyncReadWrite + Sized {
+    /// The half used to read from the transport.
+    type ReadHalf: AsyncRead + Unpin;
+    /// The half used to write to the transport.
+    type WriteHalf: AsyncWrite + Unpin;
+
+    /// Splits the transport into its read half and write half.
+    ///
+    /// # Returns
+    /// - `(Self::ReadHalf, Self::WriteHalf)`: The two halves of the transport.
+    fn split_stream(self) -> (Self::ReadHalf, Self::WriteHalf);
+}
@@ -5,14 +5,25 @@ pub type ArcStream = Arc<TcpStream>;
 /// An optional thread-safe reference-counted `TcpStream`.
 pub type OptionArcTcpStream = Option<ArcStream>;
 /// An optional thread-safe read-write locked `TcpStream` wrapper.
-pub type OptionArcRwLockStream = Option<ArcRwLockStream>;
+pub type OptionArcRwLockStream<S = TcpStream> = Option<ArcRwLockStream<S>>;
 /// A read guard for a `RwLock<TcpStream>`.
+#[deprecated(note = "`ArcRwLockStream` no longer locks the whole stream")]
 pub type RwLockReadGuardTcpStream<'a> = RwLockReadGuard<'a, TcpStream>;
 /// A write guard for a `RwLock<TcpStream>`.
+#[deprecated(note = "use `RwLockWriteGuardReadHalf` or `RwLockWriteGuardWriteHalf` instead")]
 pub type RwLockWriteGuardTcpStream<'a> = RwLockWriteGuard<'a, TcpStream>;
+/// A write guard for the buffered read half of a split stream.
+pub type RwLockWriteGuardReadHalf<'a, S = TcpStream> =
+    RwLockWriteGuard<'a, BufReader<<S as SplitStream>::ReadHalf>>;
+/// A write guard for the write half of a split stream.
+pub type RwLockWriteGuardWriteHalf<'a, S = TcpStream> =
+    RwLockWriteGuard<'a, <S as SplitStream>::WriteHalf>;
 /// A thread-safe reference to a `RwLock` write guard for `TcpStream`.
+#[deprecated(note = "`ArcRwLockStream` no longer locks the whole stream")]
 pub type ArcRwLockWriteGuardTcpStream<'a> = Arc<RwLockWriteGuard<'a, TcpStream>>;
 /// An optional thread-safe reference to a `RwLock` write guard for `TcpStream`.
+#[deprecated(note = "`ArcRwLockStream` no longer locks the whole stream")]
+#[allow(deprecated)]
 pub type OptionArcRwLockWriteGuardTcpStream<'a> = Option<ArcRwLockWriteGuardTcpStream<'a>>;
 /// A thread-safe reference to a `Mutex` guard for `TcpStream`.
 pub type ArcMutexGuardTcpStream<'a> = Arc<MutexGuard<'a, TcpStream>>;
@@ -28,3 +39,9 @@ pub type OptionSocketHost = Option<SocketHost>;
 pub type OptionSocketPort = Option<SocketPort>;
 /// An optional full socket address.
 pub type OptionSocketAddr = Option<SocketAddr>;
+/// A thread-safe read-write locked Unix domain socket stream.
+#[cfg(unix)]
+pub type ArcRwLockUnixStream = ArcRwLockStream<UnixStream>;
+/// The result of reading the credentials of the peer of a Unix domain socket.
+#[cfg(unix)]
+pub type UCredResult = Result<UCred, std::io::Error>;
@@ -0,0 +1,64 @@
+#[tokio::test]
+async fn test_tls_accept_and_connect() {
+    use crate::*;
+    use tokio::io::DuplexStream;
+    use tokio_rustls::{
+        TlsAcceptor, TlsConnector,
+        rustls::{
+            ClientConfig, RootCertStore, ServerConfig,
+            pki_types::{CertificateDer, PrivateKeyDer, PrivatePkcs8KeyDer},
+        },
+    };
+    let certified_key: rcgen::CertifiedKey =
+        rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+    let certificate: CertificateDer<'static> =
+        CertificateDer::from(certified_key.cert.der().to_vec());
+    let key: PrivateKeyDer<'static> = PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(
+        certified_key.key_pair.serialize_der(),
+    ));
+    let mut server_config: ServerConfig = ServerConfig::builder()
+        .with_no_client_auth()
+        .with_single_cert(vec![certificate.clone()], key)
+        .unwrap();
+    server_config.alpn_protocols = vec![b"http/1.1".to_vec()];
+    let mut roots: RootCertStore = RootCertStore::empty();
+    roots.add(certificate).unwrap();
+    let mut client_config: ClientConfig = ClientConfig::builder()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+    client_config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+    let acceptor: TlsAcceptor = TlsAcceptor::from(Arc::new(server_config));
+    let connector: TlsConnector = TlsConnector::from(Arc::new(client_config));
+    let (client, server) = tokio::io::duplex(4096);
+    let (server, client) = tokio::join!(
+        ArcRwLockStream::tls_accept(&acceptor, server),
+        ArcRwLockStream::tls_connect(&connector, "localhost", client)
+    );
+    let server: ArcRwLockTlsServerStream<DuplexStream> = server.unwrap();
+    let client: ArcRwLockTlsClientStream<DuplexStream> = client.unwrap();
+    let server_info: TlsInfo = server.get_tls_info().await;
+    assert_eq!(
+        server_info.get_server_name(),
+        &Some("localhost".to_string())
+    );
+    assert_eq!(server_info.get_alpn_protocol(), &Some(b"http/1.1".to_vec()));
+    assert_eq!(client.get_tls_info().await.get_peer_certificates().len(), 1);
+    client
+        .send(&b"GET /secure HTTP/1.1\r\nHost: localhost\r\n\r\n".to_vec())
+        .await
+        .unwrap();
+    client.flush().await;
+    let request: Request = Request::http_request_from_stream(&server, &RequestConfig::default())
+        .await
+        .unwrap();
+    assert_eq!(request.get_path(), "/secure");
+    let (client, server) = tokio::io::duplex(4096);
+    let (_, client) = tokio::join!(
+        ArcRwLockStream::tls_accept(&acceptor, server),
+        ArcRwLockStream::tls_connect(&connector, "example.com", client)
+    );
+    assert!(matches!(
+        client.unwrap_err(),
+        RequestError::TlsHandshake(err) if !err.is_empty()
+    ));
+}
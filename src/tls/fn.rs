@@ -0,0 +1,26 @@
+use crate::*;
+use tokio_rustls::rustls::CommonState;
+
+/// Collects the peer certificates and ALPN protocol from a TLS connection.
+///
+/// # Parameters
+/// - `state`: The state shared by server and client connections.
+/// - `server_name`: The SNI name, if known.
+///
+/// # Returns
+/// - `TlsInfo`: The negotiated details.
+pub(crate) fn tls_info_from_state(state: &CommonState, server_name: OptionString) -> TlsInfo {
+    TlsInfo {
+        alpn_protocol: state.alpn_protocol().map(|protocol| protocol.to_vec()),
+        server_name,
+        peer_certificates: state
+            .peer_certificates()
+            .map(|certificates| {
+                certificates
+                    .iter()
+                    .map(|certificate| certificate.to_vec())
+                    .collect()
+            })
+            .unwrap_or_default(),
+    }
+}
@@ -0,0 +1,173 @@
+use crate::*;
+use std::{
+    io::IoSlice,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tokio_rustls::{TlsAcceptor, TlsConnector, rustls::pki_types::ServerName};
+
+impl<S> TlsConnectionInfo for TlsServerStream<S> {
+    fn get_tls_info(&self) -> TlsInfo {
+        let (_, connection) = self.get_ref();
+        tls_info_from_state(
+            connection,
+            connection.server_name().map(|name| name.to_string()),
+        )
+    }
+}
+
+impl<S> TlsConnectionInfo for TlsClientStream<S> {
+    fn get_tls_info(&self) -> TlsInfo {
+        let (_, connection) = self.get_ref();
+        tls_info_from_state(connection, None)
+    }
+}
+
+impl<T> TlsConnectionInfo for TlsWriteHalf<T> {
+    fn get_tls_info(&self) -> TlsInfo {
+        self.tls_info.clone()
+    }
+}
+
+impl<T> TlsWriteHalf<T>
+where
+    T: AsyncReadWrite + TlsConnectionInfo,
+{
+    /// Splits a TLS stream, keeping the details of its handshake in the write half.
+    ///
+    /// # Parameters
+    /// - `stream`: The TLS stream to split.
+    ///
+    /// # Returns
+    /// - `(ReadHalf<T>, Self)`: The read half and the write half of the stream.
+    fn split(stream: T) -> (ReadHalf<T>, Self) {
+        let tls_info: TlsInfo = stream.get_tls_info();
+        let (read_half, half) = tokio::io::split(stream);
+        (read_half, Self { half, tls_info })
+    }
+}
+
+impl<T> AsyncWrite for TlsWriteHalf<T>
+where
+    T: AsyncWrite,
+{
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.half).poll_write(cx, buf)
+    }
+
+    fn poll_write_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[IoSlice<'_>],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.half).poll_write_vectored(cx, bufs)
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.half.is_write_vectored()
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.half).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.half).poll_shutdown(cx)
+    }
+}
+
+impl<S> SplitStream for TlsServerStream<S>
+where
+    S: AsyncReadWrite,
+{
+    type ReadHalf = ReadHalf<Self>;
+    type WriteHalf = TlsWriteHalf<Self>;
+
+    fn split_stream(self) -> (Self::ReadHalf, Self::WriteHalf) {
+        TlsWriteHalf::split(self)
+    }
+}
+
+impl<S> SplitStream for TlsClientStream<S>
+where
+    S: AsyncReadWrite,
+{
+    type ReadHalf = ReadHalf<Self>;
+    type WriteHalf = TlsWriteHalf<Self>;
+
+    fn split_stream(self) -> (Self::ReadHalf, Self::WriteHalf) {
+        TlsWriteHalf::split(self)
+    }
+}
+
+impl<S> ArcRwLockStream<S>
+where
+    S: SplitStream,
+    S::WriteHalf: TlsConnectionInfo,
+{
+    /// Returns the details negotiated during the TLS handshake.
+    ///
+    /// Only the write half is locked, so a pending read does not delay the call.
+    ///
+    /// # Returns
+    /// - `TlsInfo`: The ALPN protocol, SNI name and peer certificates.
+    pub async fn get_tls_info(&self) -> TlsInfo {
+        self.write_half().await.get_tls_info()
+    }
+}
+
+impl<S> ArcRwLockStream<TlsServerStream<S>>
+where
+    S: AsyncReadWrite,
+{
+    /// Performs the server side of a TLS handshake and wraps the resulting stream.
+    ///
+    /// # Parameters
+    /// - `acceptor`: The acceptor holding the server certificate and ALPN protocols.
+    /// - `stream`: The accepted transport, such as a `TcpStream`.
+    ///
+    /// # Returns
+    /// - `Ok`: The wrapped TLS stream.
+    /// - `Err`: `RequestError::TlsHandshake` if the handshake fails.
+    pub async fn tls_accept(acceptor: &TlsAcceptor, stream: S) -> Result<Self, RequestError> {
+        let tls_stream: TlsServerStream<S> = acceptor
+            .accept(stream)
+            .await
+            .map_err(|err| RequestError::TlsHandshake(err.to_string()))?;
+        Ok(Self::from_stream(tls_stream))
+    }
+}
+
+impl<S> ArcRwLockStream<TlsClientStream<S>>
+where
+    S: AsyncReadWrite,
+{
+    /// Performs the client side of a TLS handshake and wraps the resulting stream.
+    ///
+    /// # Parameters
+    /// - `connector`: The connector holding the trusted roots and ALPN protocols.
+    /// - `server_name`: The DNS name or IP address sent through SNI and verified against the certificate.
+    /// - `stream`: The connected transport, such as a `TcpStream`.
+    ///
+    /// # Returns
+    /// - `Ok`: The wrapped TLS stream.
+    /// - `Err`: `RequestError::InvalidHost` if the server name is invalid, or
+    ///   `RequestError::TlsHandshake` if the handshake fails.
+    pub async fn tls_connect(
+        connector: &TlsConnector,
+        server_name: &str,
+        stream: S,
+    ) -> Result<Self, RequestError> {
+        let name: ServerName<'static> = ServerName::try_from(server_name.to_string())
+            .map_err(|_| RequestError::InvalidHost(server_name.to_string()))?;
+        let tls_stream: TlsClientStream<S> = connector
+            .connect(name, stream)
+            .await
+            .map_err(|err| RequestError::TlsHandshake(err.to_string()))?;
+        Ok(Self::from_stream(tls_stream))
+    }
+}
@@ -0,0 +1,12 @@
+pub(crate) mod cfg;
+pub(crate) mod r#fn;
+pub(crate) mod r#impl;
+pub(crate) mod r#struct;
+pub(crate) mod r#trait;
+pub(crate) mod r#type;
+
+pub use r#struct::*;
+pub use r#trait::*;
+pub use r#type::*;
+
+pub(crate) use r#fn::*;
@@ -0,0 +1,26 @@
+use crate::*;
+
+/// Details negotiated during a TLS handshake.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Getter, DisplayDebug)]
+pub struct TlsInfo {
+    /// The application protocol selected through ALPN, e.g. `h2` or `http/1.1`.
+    pub(super) alpn_protocol: OptionVecU8,
+    /// The server name sent by the client through SNI, only known on the server side.
+    pub(super) server_name: OptionString,
+    /// The DER-encoded certificate chain presented by the peer, starting with its own certificate.
+    pub(super) peer_certificates: TlsCertificates,
+}
+
+/// The write half of a split TLS stream.
+///
+/// Keeps the details of the handshake, which can no longer be read from the
+/// connection once it is split.
+///
+/// # Fields
+/// - `half`: The write half of the TLS stream.
+/// - `tls_info`: The details negotiated during the handshake.
+#[derive(Debug)]
+pub struct TlsWriteHalf<T> {
+    pub(super) half: WriteHalf<T>,
+    pub(super) tls_info: TlsInfo,
+}
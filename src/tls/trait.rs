@@ -0,0 +1,10 @@
+use crate::*;
+
+/// A TLS stream that can report the details of its handshake.
+pub trait TlsConnectionInfo {
+    /// Returns the details negotiated during the TLS handshake.
+    ///
+    /// # Returns
+    /// - `TlsInfo`: The ALPN protocol, SNI name and peer certificates.
+    fn get_tls_info(&self) -> TlsInfo;
+}
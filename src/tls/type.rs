@@ -0,0 +1,12 @@
+use crate::*;
+
+/// DER-encoded certificates.
+pub type TlsCertificates = Vec<Vec<u8>>;
+/// A TLS stream accepted by a server over TCP.
+pub type TlsServerStream<S = TcpStream> = tokio_rustls::server::TlsStream<S>;
+/// A TLS stream connected by a client over TCP.
+pub type TlsClientStream<S = TcpStream> = tokio_rustls::client::TlsStream<S>;
+/// A thread-safe read-write locked TLS stream accepted by a server.
+pub type ArcRwLockTlsServerStream<S = TcpStream> = ArcRwLockStream<TlsServerStream<S>>;
+/// A thread-safe read-write locked TLS stream connected by a client.
+pub type ArcRwLockTlsClientStream<S = TcpStream> = ArcRwLockStream<TlsClientStream<S>>;
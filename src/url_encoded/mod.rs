@@ -0,0 +1,5 @@
+pub(crate) mod serde_urlencoded;
+pub(crate) mod r#type;
+
+pub use serde_urlencoded::*;
+pub use r#type::*;
@@ -0,0 +1,5 @@
+pub use serde_urlencoded::{
+    de::Error as UrlEncodedError, from_bytes as url_encoded_from_bytes,
+    from_str as url_encoded_from_str, ser::Error as UrlEncodedSerializeError,
+    to_string as url_encoded_to_string,
+};
@@ -0,0 +1,3 @@
+use crate::*;
+
+pub type ResultUrlEncodedError<T> = Result<T, UrlEncodedError>;